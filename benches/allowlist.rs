@@ -0,0 +1,73 @@
+// file: benches/allowlist.rs
+// description: compares allow-list lookup throughput before and after freezing
+
+use rust_tld::{AllowList, Fqdn};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Number of exact domains in the benchmarked list
+const ENTRIES: usize = 10_000;
+
+/// Number of passes over the lookup set per measured case
+const ROUNDS: u32 = 50;
+
+/// Full Public Suffix List snapshot shared with the conformance tests
+const PSL_FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/public_suffix_list.dat");
+
+/// Runs `lookup` over every input `ROUNDS` times and returns the mean time per lookup
+fn measure<F: Fn(&str) -> bool>(inputs: &[String], lookup: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for input in inputs {
+            black_box(lookup(black_box(input)));
+        }
+    }
+    start.elapsed() / (ROUNDS * inputs.len() as u32)
+}
+
+fn main() {
+    let mut entries: Vec<String> = (0..ENTRIES).map(|i| format!("site{}.com", i)).collect();
+    entries.extend((0..100).map(|i| format!("*.tenant{}.co.uk", i)));
+    let entries: Vec<&str> = entries.iter().map(String::as_str).collect();
+
+    let allow_list = AllowList::new(&entries);
+    let frozen = allow_list.clone().freeze();
+
+    // Half hits, half misses, with a share of wildcard matches and mixed case
+    let domains: Vec<String> = (0..ENTRIES)
+        .map(|i| match i % 4 {
+            0 => format!("site{}.com", i),
+            1 => format!("app.tenant{}.co.uk", i % 100),
+            2 => format!("SITE{}.COM", i + ENTRIES),
+            _ => format!("unknown{}.org", i),
+        })
+        .collect();
+    let origins: Vec<String> = domains
+        .iter()
+        .map(|domain| format!("https://www.{}/path", domain))
+        .collect();
+
+    let fqdn = Fqdn::from_file_blocking(PSL_FIXTURE, None)
+        .expect("failed to load public suffix list fixture");
+
+    for (name, elapsed) in [
+        (
+            "AllowList::allows_domain",
+            measure(&domains, |d| allow_list.allows_domain(d)),
+        ),
+        (
+            "FrozenAllowList::allows_domain",
+            measure(&domains, |d| frozen.allows_domain(d)),
+        ),
+        (
+            "AllowList::allows_with",
+            measure(&origins, |o| allow_list.allows_with(&fqdn, o)),
+        ),
+        (
+            "FrozenAllowList::allows_with",
+            measure(&origins, |o| frozen.allows_with(&fqdn, o)),
+        ),
+    ] {
+        println!("{:<32} {:>10?} per lookup", name, elapsed);
+    }
+}
@@ -0,0 +1,71 @@
+// file: benches/ascii_fast_path.rs
+// description: compares get_fqdn on lowercase ASCII hosts against splitting and joining labels
+
+use rust_tld::{Fqdn, Options};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Number of passes over the hosts per measured case
+const ROUNDS: u32 = 20_000;
+
+/// Full Public Suffix List snapshot shared with the conformance tests
+const PSL_FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/public_suffix_list.dat");
+
+/// Lowercase ASCII hosts of varying depth, all with a registrable domain
+const HOSTS: &[&str] = &[
+    "example.com",
+    "www.example.com",
+    "a.b.c.d.example.com",
+    "www.example.co.uk",
+    "cdn.assets.example.co.uk",
+    "bucket.s3.amazonaws.com",
+    "deep.host.name.with.many.labels.example.org",
+    "user.github.io",
+];
+
+/// Runs `lookup` over every host `ROUNDS` times and returns the mean time per lookup
+fn measure<F: Fn(&str) -> Option<String>>(lookup: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for host in HOSTS {
+            black_box(lookup(black_box(host)));
+        }
+    }
+    start.elapsed() / (ROUNDS * HOSTS.len() as u32)
+}
+
+/// Registrable domain the way `get_fqdn` built it before the fast path:
+/// split the host into labels and join those above the suffix
+fn split_and_join(fqdn: &Fqdn, host: &str) -> Option<String> {
+    let suffix_labels = fqdn.public_suffix(host).ok()?.split('.').count();
+    let labels: Vec<&str> = host.split('.').collect();
+    let start = labels.len().checked_sub(suffix_labels + 1)?;
+    Some(labels[start..].join("."))
+}
+
+fn main() {
+    let options = Options::new().allow_private_tlds(true);
+    let fqdn = Fqdn::from_file_blocking(PSL_FIXTURE, Some(options))
+        .expect("failed to load public suffix list fixture");
+
+    for host in HOSTS {
+        assert_eq!(
+            fqdn.get_fqdn(host).ok(),
+            split_and_join(&fqdn, host),
+            "{}",
+            host
+        );
+    }
+
+    let suffix_only = measure(|host| fqdn.public_suffix(host).ok());
+    let split = measure(|host| split_and_join(&fqdn, host));
+    let fast = measure(|host| fqdn.get_fqdn(host).ok());
+
+    for (name, elapsed) in [
+        ("public_suffix (lookup only)", suffix_only),
+        ("lookup, split and join", split),
+        ("get_fqdn (ASCII fast path)", fast),
+    ] {
+        println!("{:<32} {:>10?} per lookup", name, elapsed);
+    }
+}
@@ -0,0 +1,78 @@
+// file: benches/find_tld.rs
+// description: measures worst-case suffix lookup latency without external benchmark crates
+
+use rust_tld::{Fqdn, Options, MIN_DATA_SIZE};
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Number of lookups per measured case
+const ITERATIONS: u32 = 200_000;
+
+/// Writes a synthetic public suffix list to a temp file and loads it
+fn load_manager() -> Fqdn {
+    let mut data =
+        String::from("// publicsuffix.org benchmark data\n// ===BEGIN ICANN DOMAINS===\n");
+    for suffix in ["com", "net", "org", "uk", "co.uk", "jp", "tokyo.jp"] {
+        data.push_str(suffix);
+        data.push('\n');
+    }
+    for i in 0..5000 {
+        data.push_str(&format!("tld{i}\nsub{i}.tld{i}\n"));
+    }
+    data.push_str("// ===END ICANN DOMAINS===\n// ");
+    data.push_str(&"x".repeat(MIN_DATA_SIZE));
+
+    let path = std::env::temp_dir().join("rust_tld_bench_suffix_list.dat");
+    std::fs::write(&path, data).expect("failed to write benchmark suffix list");
+
+    let options = Options::new().public_suffix_file(path.to_string_lossy());
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("failed to build runtime")
+        .block_on(Fqdn::new(Some(options)))
+        .expect("failed to load benchmark suffix list")
+}
+
+fn main() {
+    let fqdn = load_manager();
+
+    let cases = [
+        (
+            "worst case: 5-label host, 1-dot suffix",
+            "a.b.example.co.uk",
+        ),
+        ("deep host, 0-dot suffix", "a.b.c.d.e.example.com"),
+        ("simple host", "example.com"),
+        // Wildcard, exception and deep lists are empty in this list, so most
+        // of the candidate depths are skipped without a lookup
+        ("unlisted TLD, every depth tried", "a.b.c.example.invalid"),
+    ];
+
+    for (name, host) in cases {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            let _ = black_box(fqdn.get_fqdn(black_box(host)));
+        }
+        let per_call = start.elapsed() / ITERATIONS;
+        println!("{:<42} {:>10?} per lookup", name, per_call);
+    }
+
+    // A realistic mix dominated by names directly under common gTLDs
+    let mixed = [
+        "example.com",
+        "github.com",
+        "wikipedia.org",
+        "cloudflare.net",
+        "google.com",
+        "example.co.uk",
+        "mozilla.org",
+        "amazon.com",
+    ];
+    let start = Instant::now();
+    for i in 0..ITERATIONS {
+        let host = mixed[i as usize % mixed.len()];
+        let _ = black_box(fqdn.get_fqdn(black_box(host)));
+    }
+    let per_call = start.elapsed() / ITERATIONS;
+    println!("{:<42} {:>10?} per lookup", ".com-heavy mix", per_call);
+}
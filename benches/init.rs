@@ -0,0 +1,133 @@
+// file: benches/init.rs
+// description: compares initialization from a local file against a download from a local HTTP server
+//
+// Run with `--features bench-internals` to also time initialization without
+// the list marker and entry count checks.
+
+use rust_tld::{Fqdn, Options};
+use std::hint::black_box;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+/// Number of initializations per measured case
+const ITERATIONS: u32 = 20;
+
+/// Full Public Suffix List snapshot shared with the conformance tests
+const PSL_FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/public_suffix_list.dat");
+
+/// Serves `body` as `text/plain` to every request on a background thread
+///
+/// Returns the URL of the list on the server.
+fn spawn_http_server(body: Vec<u8>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind mock server");
+    let addr = listener.local_addr().expect("mock server has no address");
+
+    std::thread::spawn(move || {
+        for mut socket in listener.incoming().flatten() {
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match socket.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+
+            let header = format!(
+                "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Type: text/plain\r\nContent-Length: {}\r\n\r\n",
+                body.len()
+            );
+            let _ = socket.write_all(header.as_bytes());
+            let _ = socket.write_all(&body);
+        }
+    });
+
+    format!("http://{}/public_suffix_list.dat", addr)
+}
+
+/// Runs `init` `ITERATIONS` times and returns the mean time per run
+fn measure<F, T>(runtime: &tokio::runtime::Runtime, init: F) -> Duration
+where
+    F: Fn() -> T,
+    T: std::future::Future,
+{
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(runtime.block_on(init()));
+    }
+    start.elapsed() / ITERATIONS
+}
+
+/// Times the same initialization with the marker and entry count checks off
+#[cfg(feature = "bench-internals")]
+fn unvalidated_init(
+    runtime: &tokio::runtime::Runtime,
+    file_options: &Options,
+    http_options: &Options,
+) -> Vec<(&'static str, Duration)> {
+    Fqdn::set_list_validation(false);
+    let results = vec![
+        (
+            "file: Fqdn::new, unvalidated",
+            measure(runtime, || Fqdn::new(Some(file_options.clone()))),
+        ),
+        (
+            "HTTP: Fqdn::new, unvalidated",
+            measure(runtime, || Fqdn::new(Some(http_options.clone()))),
+        ),
+    ];
+    Fqdn::set_list_validation(true);
+    results
+}
+
+#[cfg(not(feature = "bench-internals"))]
+fn unvalidated_init(
+    _runtime: &tokio::runtime::Runtime,
+    _file_options: &Options,
+    _http_options: &Options,
+) -> Vec<(&'static str, Duration)> {
+    println!("(build with --features bench-internals to time unvalidated initialization)");
+    Vec::new()
+}
+
+fn main() {
+    let data = std::fs::read(PSL_FIXTURE).expect("failed to read public suffix list fixture");
+    let url = spawn_http_server(data);
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to build runtime");
+
+    let file_options = Options::new().public_suffix_file(PSL_FIXTURE);
+    let http_options = Options::new().public_suffix_url(url.as_str());
+
+    // Both sources must load before their timings mean anything
+    runtime
+        .block_on(Fqdn::new(Some(file_options.clone())))
+        .expect("failed to load public suffix list from file");
+    runtime
+        .block_on(Fqdn::new(Some(http_options.clone())))
+        .expect("failed to download public suffix list from mock server");
+
+    // Full initialization: read or download, check the markers and entry
+    // count, then build and sort the rule lists
+    let file_init = measure(&runtime, || Fqdn::new(Some(file_options.clone())));
+    let http_init = measure(&runtime, || Fqdn::new(Some(http_options.clone())));
+    let mut results = vec![
+        ("file: Fqdn::new", file_init),
+        ("HTTP: Fqdn::new", http_init),
+    ];
+
+    results.extend(unvalidated_init(&runtime, &file_options, &http_options));
+
+    for (name, elapsed) in results {
+        println!("{:<32} {:>10?} per init", name, elapsed);
+    }
+    println!(
+        "{:<32} {:>10?} per init",
+        "download overhead",
+        http_init.saturating_sub(file_init)
+    );
+}
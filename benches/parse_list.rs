@@ -0,0 +1,124 @@
+// file: benches/parse_list.rs
+// description: measures loading the full public suffix list, in time and heap allocations
+//
+// Run with `--features intern` to measure the packed storage as well.
+
+use rust_tld::etld::Etld;
+use rust_tld::{Fqdn, Options, ETLD_GROUP_MAX, ICANN_BUCKET_RESERVE};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Number of loads per measurement
+const ITERATIONS: u32 = 50;
+
+/// Full Public Suffix List snapshot shared with the conformance tests
+const PSL_FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/public_suffix_list.dat");
+
+/// System allocator that counts allocations and allocated bytes
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static REALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        REALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Fills one bucket per dot count with the ICANN exact rules of the fixture
+///
+/// Returns the number of reallocations made while inserting, with and without
+/// reserving `ICANN_BUCKET_RESERVE` up front as the parser does. With the
+/// `intern` feature this includes growing the buffer the rules are packed into.
+fn bucket_reallocations(rules: &[String], reserve: bool) -> usize {
+    let buckets: Vec<Etld> = (0..ETLD_GROUP_MAX).map(Etld::new).collect();
+    if reserve {
+        for (etld, additional) in buckets.iter().zip(ICANN_BUCKET_RESERVE) {
+            etld.reserve(additional);
+        }
+    }
+
+    let before = REALLOCATIONS.load(Ordering::Relaxed);
+    for rule in rules {
+        let dots = rule.matches('.').count();
+        buckets[dots].add(rule.clone(), false);
+    }
+    let reallocations = REALLOCATIONS.load(Ordering::Relaxed) - before;
+    black_box(buckets);
+    reallocations
+}
+
+fn main() {
+    let storage = if cfg!(feature = "intern") {
+        "packed (intern)"
+    } else {
+        "one String per rule"
+    };
+    println!("rule storage: {}", storage);
+
+    for (name, options) in [
+        ("ICANN rules", Options::new()),
+        (
+            "ICANN and private rules",
+            Options::new().allow_private_tlds(true),
+        ),
+    ] {
+        let fqdn = Fqdn::from_file_blocking(PSL_FIXTURE, Some(options))
+            .expect("failed to load public suffix list fixture");
+
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(fqdn.load_public_suffix_from_file_blocking(black_box(PSL_FIXTURE)))
+                .expect("failed to reload public suffix list fixture");
+        }
+        let per_load = start.elapsed() / ITERATIONS;
+        let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / ITERATIONS as usize;
+        let bytes = (ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes) / ITERATIONS as usize;
+
+        println!(
+            "{:<26} {:>10?} per load, {:>7} allocations, {:>9} bytes allocated",
+            name, per_load, allocations, bytes
+        );
+    }
+
+    let data = std::fs::read_to_string(PSL_FIXTURE).expect("failed to read fixture");
+    let rules: Vec<String> = data
+        .lines()
+        .take_while(|line| !line.contains("===END ICANN DOMAINS==="))
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .filter(|line| !line.starts_with("*.") && !line.starts_with('!'))
+        .filter(|line| line.matches('.').count() < ETLD_GROUP_MAX)
+        .map(str::to_lowercase)
+        .collect();
+    for (name, reserve) in [("unreserved buckets", false), ("reserved buckets", true)] {
+        println!(
+            "{:<26} {:>7} reallocations filling {} ICANN rules",
+            name,
+            bucket_reallocations(&rules, reserve),
+            rules.len()
+        );
+    }
+}
@@ -5,7 +5,6 @@ use clap::{Arg, Command};
 use rust_tld::{get_fqdn, get_fqdn_sync, init, validate_origin, validate_origin_sync, Options, TldError};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
-use tokio;
 
 /// Configuration for the example application
 #[derive(Debug, Clone)]
@@ -274,7 +273,7 @@ fn get_comprehensive_test_suite(config: &Config) -> Vec<String> {
 async fn run_url_analysis(urls: &[String], config: &Config) -> Result<(), Box<dyn std::error::Error>> {
     println!("🔍 URL Analysis Results");
     println!("======================");
-    println!("{:<60} | {:<35} | {:<15} | {}", "Original URL", "Extracted FQDN", "Status", "Notes");
+    println!("{:<60} | {:<35} | {:<15} | Notes", "Original URL", "Extracted FQDN", "Status");
     println!("{}", "=".repeat(130));
 
     let mut stats = AnalysisStats::new();
@@ -308,7 +307,7 @@ async fn run_url_analysis(urls: &[String], config: &Config) -> Result<(), Box<dy
                     truncate(url, 60), 
                     "-", 
                     "❌ ERROR", 
-                    format!("{}: {}", error_type, err)
+                    format_args!("{}: {}", error_type, err)
                 );
                 
                 if config.verbose {
@@ -364,7 +363,7 @@ async fn demonstrate_origin_validation(config: &Config) -> Result<(), Box<dyn st
         ("https://localhost:3000", true),
     ];
 
-    println!("{:<50} | {:<10} | {:<10} | {}", "Origin", "Expected", "Actual", "Result");
+    println!("{:<50} | {:<10} | {:<10} | Result", "Origin", "Expected", "Actual");
     println!("{}", "-".repeat(85));
 
     for (origin, expected) in test_origins {
@@ -648,20 +647,23 @@ fn analyze_url_complexity(url: &str, fqdn: &str) -> String {
 /// Classify error types for better reporting
 fn classify_error(error: &TldError) -> String {
     match error {
-        TldError::InvalidUrl => "Invalid URL".to_string(),
-        TldError::InvalidTld => "Invalid TLD".to_string(),
+        TldError::InvalidUrl(_) => "Invalid URL".to_string(),
+        TldError::InvalidTld(_) => "Invalid TLD".to_string(),
         TldError::PublicSuffixDownload(_) => "Download Error".to_string(),
         TldError::PublicSuffixParse(_) => "Parse Error".to_string(),
         TldError::PublicSuffixFormat(_) => "Format Error".to_string(),
+        TldError::InputFile(_) => "Input File Error".to_string(),
+        TldError::InvalidOptions(_) => "Options Error".to_string(),
     }
 }
 
 /// Truncate string to specified length with ellipsis
 fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
+    if s.chars().count() <= max_len {
         s.to_string()
     } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
+        let kept: String = s.chars().take(max_len.saturating_sub(3)).collect();
+        format!("{}...", kept)
     }
 }
 
@@ -730,7 +732,7 @@ mod tests {
         assert_eq!(truncate("this is a very long string", 10), "this is...");
         
         // Test error classification
-        let error = TldError::InvalidUrl;
+        let error = TldError::InvalidUrl(None);
         assert_eq!(classify_error(&error), "Invalid URL");
     }
 
@@ -747,7 +749,7 @@ mod tests {
         let mut stats = AnalysisStats::new();
         
         stats.record_success(Duration::from_millis(5));
-        stats.record_error(&TldError::InvalidUrl, Duration::from_millis(2));
+        stats.record_error(&TldError::InvalidUrl(None), Duration::from_millis(2));
         
         assert_eq!(stats.total_processed, 2);
         assert_eq!(stats.successful, 1);
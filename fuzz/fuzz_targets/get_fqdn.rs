@@ -0,0 +1,43 @@
+// file: fuzz/fuzz_targets/get_fqdn.rs
+// description: fuzz target asserting that get_fqdn never panics on arbitrary input
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_tld::{Fqdn, Options, MIN_DATA_SIZE};
+use std::sync::OnceLock;
+
+static MANAGER: OnceLock<Fqdn> = OnceLock::new();
+
+/// Builds a small but valid public suffix list so the fuzzer runs offline
+fn manager() -> &'static Fqdn {
+    MANAGER.get_or_init(|| {
+        let mut data = String::from("// publicsuffix.org fuzz data\n// ===BEGIN ICANN DOMAINS===\n");
+        for suffix in ["com", "org", "uk", "co.uk", "jp", "xn--p1ai", "рф", "a.b.c.d.e"] {
+            data.push_str(suffix);
+            data.push('\n');
+        }
+        for i in 0..1000 {
+            data.push_str(&format!("tld{i}\n"));
+        }
+        data.push_str("// ===END ICANN DOMAINS===\n// ");
+        data.push_str(&"x".repeat(MIN_DATA_SIZE));
+
+        let path = std::env::temp_dir().join("rust_tld_fuzz_suffix_list.dat");
+        std::fs::write(&path, data).expect("failed to write fuzz suffix list");
+
+        let options = Options::new().public_suffix_file(path.to_string_lossy());
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("failed to build runtime")
+            .block_on(Fqdn::new(Some(options)))
+            .expect("failed to load fuzz suffix list")
+    })
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        // Any outcome is fine as long as it is an Ok/Err and not a panic
+        let _ = manager().get_fqdn(input);
+    }
+});
@@ -0,0 +1,622 @@
+// file: src/allowlist.rs
+// description: precomputed allow-list of registrable domains for repeated origin validation
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::FqdnManager;
+
+/// Precomputed set of allowed registrable domains and wildcard patterns
+///
+/// Entries are normalized once at construction so that each validation is a
+/// constant number of hash lookups instead of a linear scan of the list.
+///
+/// Two kinds of entries are supported:
+/// - Exact domains such as `example.com`, matched against the origin's FQDN
+/// - Wildcard patterns such as `*.example.com` or `*.gov.uk`, matching the
+///   base domain itself and any registrable domain beneath it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowList {
+    /// Exact registrable domains
+    domains: HashSet<String>,
+    /// Base domains of wildcard patterns (without the leading `*.`)
+    patterns: HashSet<String>,
+}
+
+impl AllowList {
+    /// Creates a new allow-list from a list of domains and wildcard patterns
+    ///
+    /// Entries are trimmed, lowercased and stripped of any trailing dot.
+    /// Empty entries and bare `*` wildcards are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `origins` - Allowed FQDNs (`example.com`) or patterns (`*.example.com`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::AllowList;
+    ///
+    /// let allow_list = AllowList::new(&["example.com", "*.gov.uk"]);
+    /// assert!(allow_list.allows_domain("example.com"));
+    /// assert!(allow_list.allows_domain("service.gov.uk"));
+    /// assert!(!allow_list.allows_domain("malicious.com"));
+    /// ```
+    pub fn new(origins: &[&str]) -> Self {
+        let mut allow_list = Self::default();
+
+        for origin in origins {
+            let entry = origin.trim().trim_end_matches('.').to_lowercase();
+            if let Some(base) = entry.strip_prefix("*.") {
+                allow_list.patterns.insert(base.to_string());
+            } else if !entry.is_empty() && entry != "*" {
+                allow_list.domains.insert(entry);
+            }
+        }
+
+        allow_list
+    }
+
+    /// Returns the number of exact domains and patterns in the list
+    pub fn len(&self) -> usize {
+        self.domains.len() + self.patterns.len()
+    }
+
+    /// Checks if the list contains no entries
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty() && self.patterns.is_empty()
+    }
+
+    /// Checks whether an already-resolved registrable domain is allowed
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - A registrable domain such as returned by `get_fqdn`
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the domain is listed or falls under a wildcard pattern
+    /// * `false` - Otherwise
+    pub fn allows_domain(&self, domain: &str) -> bool {
+        let domain = domain.trim_end_matches('.').to_lowercase();
+        if self.domains.contains(&domain) {
+            return true;
+        }
+
+        // Walk the domain and each of its parents looking for a pattern base
+        let mut candidate = domain.as_str();
+        loop {
+            if self.patterns.contains(candidate) {
+                return true;
+            }
+            match candidate.split_once('.') {
+                Some((_, parent)) => candidate = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Checks whether an origin is allowed, resolving it with the given manager
+    ///
+    /// # Arguments
+    ///
+    /// * `manager` - The FQDN manager used to resolve the origin
+    /// * `origin` - The origin URL to validate
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the origin's FQDN is allowed
+    /// * `false` - If the origin is invalid or not allowed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rust_tld::{AllowList, Fqdn};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let fqdn = Fqdn::new(None).await?;
+    ///     let allow_list = AllowList::new(&["example.com"]);
+    ///
+    ///     assert!(allow_list.allows_with(&fqdn, "https://www.example.com"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn allows_with<M: FqdnManager + ?Sized>(&self, manager: &M, origin: &str) -> bool {
+        match manager.get_fqdn(origin) {
+            Ok(fqdn) => self.allows_domain(&fqdn),
+            Err(_) => false,
+        }
+    }
+
+    /// Checks whether an origin is allowed using the global manager
+    ///
+    /// This is the precomputed equivalent of `validate_origin`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin URL to validate
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the origin's FQDN is allowed
+    /// * `false` - If the origin is invalid or not allowed
+    pub async fn allows(&self, origin: &str) -> bool {
+        match crate::get_fqdn(origin).await {
+            Ok(fqdn) => self.allows_domain(&fqdn),
+            Err(_) => false,
+        }
+    }
+
+    /// Synchronous version of `allows` (requires tokio runtime)
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if called outside of a tokio runtime context.
+    pub fn allows_sync(&self, origin: &str) -> bool {
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(self.allows(origin))
+        })
+    }
+
+    /// Packs the list into an immutable `FrozenAllowList` for faster lookups
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::AllowList;
+    ///
+    /// let frozen = AllowList::new(&["example.com", "*.gov.uk"]).freeze();
+    /// assert!(frozen.allows_domain("example.com"));
+    /// assert!(frozen.allows_domain("service.gov.uk"));
+    /// assert!(!frozen.allows_domain("malicious.com"));
+    /// ```
+    pub fn freeze(self) -> FrozenAllowList {
+        FrozenAllowList {
+            domains: FrozenSet::new(self.domains),
+            patterns: FrozenSet::new(self.patterns),
+        }
+    }
+}
+
+/// Immutable allow-list packed for fast repeated lookups
+///
+/// Created by `AllowList::freeze` for large, static lists checked on every
+/// request. All entries share one contiguous buffer and are found through an
+/// open-addressing table, so a lookup touches a few adjacent cache lines and
+/// allocates nothing for lowercase input. Matching is the same as `AllowList`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrozenAllowList {
+    /// Exact registrable domains
+    domains: FrozenSet,
+    /// Base domains of wildcard patterns (without the leading `*.`)
+    patterns: FrozenSet,
+}
+
+impl FrozenAllowList {
+    /// Returns the number of exact domains and patterns in the list
+    pub fn len(&self) -> usize {
+        self.domains.len() + self.patterns.len()
+    }
+
+    /// Checks if the list contains no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks whether an already-resolved registrable domain is allowed
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - A registrable domain such as returned by `get_fqdn`
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the domain is listed or falls under a wildcard pattern
+    /// * `false` - Otherwise
+    pub fn allows_domain(&self, domain: &str) -> bool {
+        let domain = domain.trim_end_matches('.');
+        let domain = if domain.is_ascii() && !domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Borrowed(domain)
+        } else {
+            Cow::Owned(domain.to_lowercase())
+        };
+        if self.domains.contains(&domain) {
+            return true;
+        }
+
+        // Walk the domain and each of its parents looking for a pattern base
+        let mut candidate = domain.as_ref();
+        loop {
+            if self.patterns.contains(candidate) {
+                return true;
+            }
+            match candidate.split_once('.') {
+                Some((_, parent)) => candidate = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Checks whether an origin is allowed, resolving it with the given manager
+    ///
+    /// # Arguments
+    ///
+    /// * `manager` - The FQDN manager used to resolve the origin
+    /// * `origin` - The origin URL to validate
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the origin's FQDN is allowed
+    /// * `false` - If the origin is invalid or not allowed
+    pub fn allows_with<M: FqdnManager + ?Sized>(&self, manager: &M, origin: &str) -> bool {
+        match manager.get_fqdn(origin) {
+            Ok(fqdn) => self.allows_domain(&fqdn),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Set of strings in one buffer, indexed by an open-addressing hash table
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FrozenSet {
+    /// Every entry, concatenated in sorted order
+    bytes: String,
+    /// Start and length of each entry in `bytes`
+    spans: Vec<(u32, u32)>,
+    /// Power-of-two table of `span index + 1`, with 0 marking an empty slot
+    slots: Vec<u32>,
+    /// Shift that keeps the top hash bits as a slot number
+    shift: u32,
+}
+
+impl FrozenSet {
+    /// Packs the entries, keeping the table at most half full
+    fn new(entries: HashSet<String>) -> Self {
+        // Sorted so that equal sets are packed identically
+        let mut entries: Vec<String> = entries.into_iter().collect();
+        entries.sort_unstable();
+
+        let capacity = (entries.len() * 2).next_power_of_two();
+        let mut set = Self {
+            bytes: String::with_capacity(entries.iter().map(String::len).sum()),
+            spans: Vec::with_capacity(entries.len()),
+            slots: vec![0; capacity],
+            shift: u64::BITS - capacity.trailing_zeros(),
+        };
+        let mask = capacity - 1;
+        for entry in &entries {
+            set.spans.push((set.bytes.len() as u32, entry.len() as u32));
+            set.bytes.push_str(entry);
+
+            let mut slot = set.slot(entry);
+            while set.slots[slot] != 0 {
+                slot = (slot + 1) & mask;
+            }
+            set.slots[slot] = set.spans.len() as u32;
+        }
+        set
+    }
+
+    /// Returns the number of entries
+    fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Checks whether the set holds `key`
+    fn contains(&self, key: &str) -> bool {
+        let mask = self.slots.len() - 1;
+        let mut slot = self.slot(key);
+        loop {
+            let index = match self.slots[slot] {
+                0 => return false,
+                index => index as usize - 1,
+            };
+            let (start, len) = self.spans[index];
+            if &self.bytes[start as usize..(start + len) as usize] == key {
+                return true;
+            }
+            slot = (slot + 1) & mask;
+        }
+    }
+
+    /// Home slot of `key`, from a multiplicative hash over 8-byte words
+    ///
+    /// The top bits of a multiplication mix best, so those pick the slot.
+    fn slot(&self, key: &str) -> usize {
+        const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+        let mut hash = key.len() as u64;
+        for chunk in key.as_bytes().chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            hash = (hash.rotate_left(5) ^ u64::from_le_bytes(word)).wrapping_mul(SEED);
+        }
+        // A one-slot table has a shift of 64, which `checked_shr` maps to 0
+        hash.checked_shr(self.shift).unwrap_or(0) as usize
+    }
+}
+
+/// Builder for an `OriginValidator`
+///
+/// Collects allowed origins and wildcard patterns; `build` normalizes them once
+/// against a manager.
+#[derive(Debug, Clone, Default)]
+pub struct OriginValidatorBuilder {
+    /// Allowed origins or hosts, resolved to registrable domains on build
+    origins: Vec<String>,
+    /// Wildcard patterns such as `*.internal`
+    wildcards: Vec<String>,
+}
+
+impl OriginValidatorBuilder {
+    /// Allows an origin, URL or host
+    ///
+    /// The entry is reduced to its registrable domain when the validator is
+    /// built, so `https://app.example.com` allows every origin under
+    /// `example.com`. Entries that cannot be resolved are ignored.
+    pub fn allow<S: Into<String>>(mut self, origin: S) -> Self {
+        self.origins.push(origin.into());
+        self
+    }
+
+    /// Allows a wildcard pattern such as `*.internal` or `*.example.com`
+    ///
+    /// The pattern matches its base domain and any registrable domain beneath
+    /// it. A pattern given without the leading `*.` is treated as if it had one.
+    pub fn allow_wildcard<S: Into<String>>(mut self, pattern: S) -> Self {
+        let pattern = pattern.into();
+        let pattern = if pattern.trim().starts_with("*.") {
+            pattern
+        } else {
+            format!("*.{}", pattern.trim())
+        };
+        self.wildcards.push(pattern);
+        self
+    }
+
+    /// Builds the validator, normalizing every entry with the given manager
+    ///
+    /// # Arguments
+    ///
+    /// * `manager` - The manager used to normalize entries and resolve origins
+    pub fn build<M: Into<Arc<dyn FqdnManager + Send + Sync>>>(self, manager: M) -> OriginValidator {
+        let manager = manager.into();
+        let domains: Vec<String> = self
+            .origins
+            .iter()
+            .filter_map(|origin| manager.get_fqdn(origin.trim()).ok())
+            .chain(self.wildcards)
+            .collect();
+        let entries: Vec<&str> = domains.iter().map(String::as_str).collect();
+
+        OriginValidator {
+            manager,
+            allow_list: AllowList::new(&entries),
+        }
+    }
+}
+
+/// Reusable origin validator with normalization done up front
+///
+/// The ergonomic, precomputed equivalent of `validate_origin`: allowed entries
+/// are reduced to registrable domains once, and each check resolves only the
+/// incoming origin.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rust_tld::{Fqdn, OriginValidator};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let fqdn = Fqdn::new(None).await?;
+///     let validator = OriginValidator::builder()
+///         .allow("https://app.example.com")
+///         .allow_wildcard("*.gov.uk")
+///         .build(fqdn);
+///
+///     assert!(validator.check("https://www.example.com"));
+///     assert!(validator.check("https://service.gov.uk"));
+///     assert!(!validator.check("https://malicious.com"));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct OriginValidator {
+    /// Manager used to resolve incoming origins
+    manager: Arc<dyn FqdnManager + Send + Sync>,
+    /// Normalized allowed domains and patterns
+    allow_list: AllowList,
+}
+
+impl OriginValidator {
+    /// Creates a builder for an origin validator
+    pub fn builder() -> OriginValidatorBuilder {
+        OriginValidatorBuilder::default()
+    }
+
+    /// Returns the normalized allow-list the validator checks against
+    pub fn allow_list(&self) -> &AllowList {
+        &self.allow_list
+    }
+
+    /// Checks whether an origin is allowed
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin URL to validate
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the origin's FQDN is allowed
+    /// * `false` - If the origin is invalid or not allowed
+    pub fn check(&self, origin: &str) -> bool {
+        self.allow_list.allows_with(self.manager.as_ref(), origin)
+    }
+}
+
+impl fmt::Debug for OriginValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OriginValidator")
+            .field("allow_list", &self.allow_list)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TldError;
+
+    /// Resolves hosts to their last two labels, enough for these tests
+    struct TwoLabelManager;
+
+    impl FqdnManager for TwoLabelManager {
+        fn get_fqdn(&self, url: &str) -> Result<String, TldError> {
+            let host = url.split("://").last().unwrap_or(url);
+            let host = host.split(['/', ':']).next().unwrap_or(host);
+            let labels: Vec<&str> = host.split('.').collect();
+            if labels.len() < 2 {
+                return Err(TldError::InvalidUrl(None));
+            }
+            Ok(labels[labels.len() - 2..].join("."))
+        }
+    }
+
+    #[test]
+    fn test_new_normalizes_entries() {
+        let allow_list = AllowList::new(&[" Example.COM ", "trusted.org.", "", "*.", "*.Gov.UK"]);
+        assert_eq!(allow_list.len(), 3);
+        assert!(!allow_list.is_empty());
+        assert!(allow_list.allows_domain("example.com"));
+        assert!(allow_list.allows_domain("trusted.org"));
+        assert!(allow_list.allows_domain("service.gov.uk"));
+    }
+
+    #[test]
+    fn test_exact_membership() {
+        let allow_list = AllowList::new(&["example.com", "trusted.org"]);
+        assert!(allow_list.allows_domain("example.com"));
+        assert!(allow_list.allows_domain("EXAMPLE.com."));
+        assert!(!allow_list.allows_domain("malicious.com"));
+        assert!(!allow_list.allows_domain("example.com.evil.net"));
+        assert!(!allow_list.allows_domain("com"));
+    }
+
+    #[test]
+    fn test_wildcard_patterns() {
+        let allow_list = AllowList::new(&["*.example.com", "*.gov.uk"]);
+        assert!(allow_list.allows_domain("example.com"));
+        assert!(allow_list.allows_domain("api.example.com"));
+        assert!(allow_list.allows_domain("service.gov.uk"));
+        assert!(!allow_list.allows_domain("gov.uk.evil.com"));
+        assert!(!allow_list.allows_domain("notexample.com"));
+    }
+
+    #[test]
+    fn test_allows_with_manager() {
+        let allow_list = AllowList::new(&["example.com"]);
+        assert!(allow_list.allows_with(&TwoLabelManager, "https://www.example.com/path"));
+        assert!(!allow_list.allows_with(&TwoLabelManager, "https://malicious.com"));
+        assert!(!allow_list.allows_with(&TwoLabelManager, "invalid"));
+    }
+
+    #[test]
+    fn test_validator_exact() {
+        let validator = OriginValidator::builder()
+            .allow("example.com")
+            .allow("trusted.org")
+            .build(Arc::new(TwoLabelManager) as Arc<dyn FqdnManager + Send + Sync>);
+        assert!(validator.check("https://example.com"));
+        assert!(validator.check("https://trusted.org/path"));
+        assert!(!validator.check("https://malicious.com"));
+        assert!(!validator.check("invalid"));
+    }
+
+    #[test]
+    fn test_validator_wildcard() {
+        let validator = OriginValidator::builder()
+            .allow_wildcard("*.internal")
+            .allow_wildcard("corp.example")
+            .build(Arc::new(TwoLabelManager) as Arc<dyn FqdnManager + Send + Sync>);
+        assert_eq!(validator.allow_list().len(), 2);
+        assert!(validator.check("https://api.service.internal"));
+        assert!(validator.check("https://www.corp.example"));
+        assert!(!validator.check("https://internal.evil.com"));
+    }
+
+    #[test]
+    fn test_validator_normalizes_entries() {
+        let validator = OriginValidator::builder()
+            .allow("https://App.Example.com:8443/login")
+            .allow("not-a-domain")
+            .build(Arc::new(TwoLabelManager) as Arc<dyn FqdnManager + Send + Sync>);
+
+        // Only the resolvable entry is kept, as its registrable domain
+        assert_eq!(validator.allow_list(), &AllowList::new(&["example.com"]));
+        assert!(validator.check("https://example.com"));
+        assert!(validator.check("https://www.example.com"));
+        assert!(!validator.check("https://example.org"));
+    }
+
+    #[test]
+    fn test_frozen_matches_unfrozen() {
+        let mut entries: Vec<String> = (0..500).map(|i| format!("site{}.com", i)).collect();
+        entries.extend(["*.gov.uk".to_string(), "*.example.org".to_string()]);
+        entries.push(" Trusted.NET. ".to_string());
+        let entries: Vec<&str> = entries.iter().map(String::as_str).collect();
+
+        let allow_list = AllowList::new(&entries);
+        let frozen = allow_list.clone().freeze();
+        assert_eq!(frozen.len(), allow_list.len());
+        assert!(!frozen.is_empty());
+        assert_eq!(frozen, AllowList::new(&entries).freeze());
+
+        for domain in [
+            "site0.com",
+            "site499.com",
+            "SITE42.COM.",
+            "site500.com",
+            "trusted.net",
+            "service.gov.uk",
+            "gov.uk",
+            "gov.uk.evil.com",
+            "example.org",
+            "a.b.example.org",
+            "notexample.org",
+            "com",
+            "",
+            "ｅxample.org",
+        ] {
+            assert_eq!(
+                frozen.allows_domain(domain),
+                allow_list.allows_domain(domain),
+                "{:?}",
+                domain
+            );
+        }
+
+        assert!(frozen.allows_with(&TwoLabelManager, "https://www.site7.com/path"));
+        assert!(!frozen.allows_with(&TwoLabelManager, "https://malicious.com"));
+        assert!(!frozen.allows_with(&TwoLabelManager, "invalid"));
+    }
+
+    #[test]
+    fn test_frozen_empty_list() {
+        let frozen = AllowList::new(&[]).freeze();
+        assert!(frozen.is_empty());
+        assert!(!frozen.allows_domain("example.com"));
+        assert!(!frozen.allows_domain(""));
+    }
+
+    #[test]
+    fn test_empty_list() {
+        let allow_list = AllowList::new(&[]);
+        assert!(allow_list.is_empty());
+        assert_eq!(allow_list.len(), 0);
+        assert!(!allow_list.allows_domain("example.com"));
+    }
+}
@@ -1,11 +1,71 @@
 // file: src/constants.rs
 // description: defines constants for the package
 
+use std::time::Duration;
+
 /// Maximum number of groups in a domain
 pub const ETLD_GROUP_MAX: usize = 5;
 
+/// Capacity reserved in each exact-rule bucket before parsing, by dot count
+///
+/// Sized from the ICANN section of the list, which holds about 1,500
+/// single-label, 3,900 two-label and 2,000 three-label rules, so that a parse
+/// fills the buckets without reallocating them.
+pub const ICANN_BUCKET_RESERVE: [usize; ETLD_GROUP_MAX] = [1536, 4096, 2048, 16, 0];
+
+/// Extra capacity reserved per bucket when `Options::allow_private_tlds` is set
+///
+/// Sized from the private section, mostly two-label rules such as `github.io`.
+pub const PRIVATE_BUCKET_RESERVE: [usize; ETLD_GROUP_MAX] = [16, 1664, 384, 96, 96];
+
+/// Average length in bytes of a rule with a given dot count, rounded up
+///
+/// Measured over both sections of the list. With the `intern` feature, rules
+/// share one buffer per bucket, which is reserved from these lengths.
+pub const AVERAGE_RULE_LEN: [usize; ETLD_GROUP_MAX] = [6, 11, 17, 25, 39];
+
+/// Common single-label gTLDs checked before the full suffix search
+///
+/// Names directly under these, such as `example.com`, make up most lookups.
+/// At most 32 entries, as they are tracked in a bitmask.
+pub const FAST_PATH_TLDS: &[&str] = &[
+    "com", "net", "org", "info", "biz", "edu", "gov", "mil", "int", "app", "dev", "xyz",
+];
+
+/// Special-use names treated as public suffixes when `Options::special_use_tlds` is set
+///
+/// Reserved by RFC 6761 (`localhost`, `test`, `invalid`, `example`), RFC 6762
+/// (`local`) and RFC 8375 (`home.arpa`). None of them is in the public suffix list.
+pub const SPECIAL_USE_TLDS: &[&str] = &[
+    "localhost",
+    "test",
+    "invalid",
+    "example",
+    "local",
+    "home.arpa",
+];
+
 /// URL to download the public suffix list from
 pub const PUBLIC_SUFFIX_FILE_URL: &str = "https://publicsuffix.org/list/public_suffix_list.dat";
 
+/// Snapshot of the public suffix list compiled into the binary
+#[cfg(feature = "embedded")]
+pub const EMBEDDED_PUBLIC_SUFFIX_LIST: &[u8] = include_bytes!("../data/public_suffix_list.dat");
+
 /// Minimum size of the public suffix list file in bytes
 pub const MIN_DATA_SIZE: usize = 32768;
+
+/// Default maximum size of a downloaded public suffix list in bytes
+pub const MAX_DOWNLOAD_SIZE: usize = 10 * 1024 * 1024;
+
+/// Content types accepted for a downloaded public suffix list, matched as substrings
+pub const ACCEPTABLE_CONTENT_TYPES: &[&str] = &["text/", "application/octet-stream"];
+
+/// Longest input host echoed in verbose error messages, in characters
+pub const MAX_ERROR_HOST_LEN: usize = 64;
+
+/// Shortest accepted HTTP request timeout
+pub const MIN_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Longest accepted HTTP request timeout
+pub const MAX_TIMEOUT: Duration = Duration::from_secs(10 * 60);
@@ -13,14 +13,16 @@ pub enum TldError {
     /// Invalid URL provided
     /// 
     /// This error occurs when the provided URL string cannot be parsed
-    /// or is malformed in some way.
-    InvalidUrl,
+    /// or is malformed in some way. Carries an optional reason such as
+    /// `"missing host"` when the failure can be pinpointed.
+    InvalidUrl(Option<String>),
     
     /// TLD not found in the public suffix list
     /// 
     /// This error occurs when the domain's TLD is not recognized
-    /// according to the Mozilla Public Suffix List.
-    InvalidTld,
+    /// according to the Mozilla Public Suffix List. Carries optional context
+    /// such as the offending host when verbose errors are enabled.
+    InvalidTld(Option<String>),
     
     /// Failed to download public suffix file
     /// 
@@ -39,16 +41,32 @@ pub enum TldError {
     /// This error occurs when the loaded file doesn't contain the
     /// expected Mozilla Public Suffix List format or markers.
     PublicSuffixFormat(String),
+
+    /// Failed to read an input file
+    /// 
+    /// This error occurs when a file of URLs to resolve cannot be
+    /// opened or read, or is not valid UTF-8.
+    InputFile(String),
+
+    /// Options are out of range
+    /// 
+    /// This error occurs when configuration options fail validation,
+    /// such as a zero or excessively long timeout.
+    InvalidOptions(String),
 }
 
 impl fmt::Display for TldError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            TldError::InvalidUrl => write!(f, "invalid URL"),
-            TldError::InvalidTld => write!(f, "invalid TLD"),
+            TldError::InvalidUrl(None) => write!(f, "invalid URL"),
+            TldError::InvalidUrl(Some(reason)) => write!(f, "invalid URL: {reason}"),
+            TldError::InvalidTld(None) => write!(f, "invalid TLD"),
+            TldError::InvalidTld(Some(context)) => write!(f, "invalid TLD: {context}"),
             TldError::PublicSuffixDownload(msg) => write!(f, "failed to download public suffix file: {msg}"),
             TldError::PublicSuffixParse(msg) => write!(f, "failed to parse public suffix file: {msg}"),
             TldError::PublicSuffixFormat(msg) => write!(f, "file is not the public suffix file: {msg}"),
+            TldError::InputFile(msg) => write!(f, "failed to read input file: {msg}"),
+            TldError::InvalidOptions(msg) => write!(f, "invalid options: {msg}"),
         }
     }
 }
@@ -107,7 +125,7 @@ pub fn wrap_error(err: Box<dyn Error>, msg: &str) -> TldError {
 /// 
 /// # Returns
 /// 
-/// A `TldError::InvalidUrl` variant
+/// A `TldError::InvalidUrl` variant carrying the context as its reason
 /// 
 /// # Examples
 /// 
@@ -115,13 +133,10 @@ pub fn wrap_error(err: Box<dyn Error>, msg: &str) -> TldError {
 /// use rust_tld::errors::invalid_url_error;
 /// 
 /// let error = invalid_url_error(Some("URL too short"));
-/// // Error will include the context in internal logging
+/// assert_eq!(error.to_string(), "invalid URL: URL too short");
 /// ```
 pub fn invalid_url_error(context: Option<&str>) -> TldError {
-    // For now, InvalidUrl doesn't include context, but we could extend this
-    // in the future if needed for better error reporting
-    let _ = context; // Acknowledge parameter to avoid warnings
-    TldError::InvalidUrl
+    TldError::InvalidUrl(context.map(str::to_string))
 }
 
 /// Creates a `TldError::InvalidTld` with optional context
@@ -135,7 +150,7 @@ pub fn invalid_url_error(context: Option<&str>) -> TldError {
 /// 
 /// # Returns
 /// 
-/// A `TldError::InvalidTld` variant
+/// A `TldError::InvalidTld` variant carrying the context
 /// 
 /// # Examples
 /// 
@@ -143,13 +158,10 @@ pub fn invalid_url_error(context: Option<&str>) -> TldError {
 /// use rust_tld::errors::invalid_tld_error;
 /// 
 /// let error = invalid_tld_error(Some("TLD not in public suffix list"));
-/// // Error context can be used for debugging
+/// assert_eq!(error.to_string(), "invalid TLD: TLD not in public suffix list");
 /// ```
 pub fn invalid_tld_error(context: Option<&str>) -> TldError {
-    // For now, InvalidTld doesn't include context, but we could extend this
-    // in the future if needed for better error reporting
-    let _ = context; // Acknowledge parameter to avoid warnings
-    TldError::InvalidTld
+    TldError::InvalidTld(context.map(str::to_string))
 }
 
 /// Type alias for Results that return TldError
@@ -177,8 +189,16 @@ mod tests {
     #[test]
     fn test_error_display() {
         let errors = vec![
-            (TldError::InvalidUrl, "invalid URL"),
-            (TldError::InvalidTld, "invalid TLD"),
+            (TldError::InvalidUrl(None), "invalid URL"),
+            (
+                TldError::InvalidUrl(Some("missing host".to_string())),
+                "invalid URL: missing host"
+            ),
+            (TldError::InvalidTld(None), "invalid TLD"),
+            (
+                TldError::InvalidTld(Some("host \"a.example\"".to_string())),
+                "invalid TLD: host \"a.example\""
+            ),
             (
                 TldError::PublicSuffixDownload("network error".to_string()),
                 "failed to download public suffix file: network error"
@@ -191,6 +211,14 @@ mod tests {
                 TldError::PublicSuffixFormat("not PSL file".to_string()),
                 "file is not the public suffix file: not PSL file"
             ),
+            (
+                TldError::InputFile("permission denied".to_string()),
+                "failed to read input file: permission denied"
+            ),
+            (
+                TldError::InvalidOptions("timeout is zero".to_string()),
+                "invalid options: timeout is zero"
+            ),
         ];
 
         for (error, expected) in errors {
@@ -200,14 +228,14 @@ mod tests {
 
     #[test]
     fn test_error_equality() {
-        assert_eq!(TldError::InvalidUrl, TldError::InvalidUrl);
-        assert_eq!(TldError::InvalidTld, TldError::InvalidTld);
+        assert_eq!(TldError::InvalidUrl(None), TldError::InvalidUrl(None));
+        assert_eq!(TldError::InvalidTld(None), TldError::InvalidTld(None));
         assert_eq!(
             TldError::PublicSuffixDownload("test".to_string()),
             TldError::PublicSuffixDownload("test".to_string())
         );
         
-        assert_ne!(TldError::InvalidUrl, TldError::InvalidTld);
+        assert_ne!(TldError::InvalidUrl(None), TldError::InvalidTld(None));
         assert_ne!(
             TldError::PublicSuffixDownload("test1".to_string()),
             TldError::PublicSuffixDownload("test2".to_string())
@@ -251,7 +279,7 @@ mod tests {
         }
         
         // Test default case
-        let other_error = io::Error::new(io::ErrorKind::Other, "other error");
+        let other_error = io::Error::other("other error");
         let wrapped = wrap_error(Box::new(other_error), "something else");
         match wrapped {
             TldError::PublicSuffixDownload(msg) => {
@@ -264,7 +292,7 @@ mod tests {
 
     #[test]
     fn test_wrap_existing_tld_error() {
-        let existing_error = TldError::InvalidUrl;
+        let existing_error = TldError::InvalidUrl(None);
         let wrapped = wrap_error(Box::new(existing_error.clone()), "additional context");
         
         // Should return the original TldError unchanged
@@ -274,17 +302,17 @@ mod tests {
     #[test]
     fn test_convenience_functions() {
         let url_error = invalid_url_error(Some("test context"));
-        assert_eq!(url_error, TldError::InvalidUrl);
+        assert_eq!(url_error, TldError::InvalidUrl(Some("test context".to_string())));
         
         let tld_error = invalid_tld_error(Some("test context"));
-        assert_eq!(tld_error, TldError::InvalidTld);
+        assert_eq!(tld_error, TldError::InvalidTld(Some("test context".to_string())));
         
         // Test without context
         let url_error = invalid_url_error(None);
-        assert_eq!(url_error, TldError::InvalidUrl);
+        assert_eq!(url_error, TldError::InvalidUrl(None));
         
         let tld_error = invalid_tld_error(None);
-        assert_eq!(tld_error, TldError::InvalidTld);
+        assert_eq!(tld_error, TldError::InvalidTld(None));
     }
 
     #[test]
@@ -314,7 +342,7 @@ mod tests {
         }
         
         fn test_error_function() -> TldResult<String> {
-            Err(TldError::InvalidUrl)
+            Err(TldError::InvalidUrl(None))
         }
         
         assert!(test_function().is_ok());
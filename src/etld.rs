@@ -1,6 +1,7 @@
 // file: src/etld.rs
 // description: manages effective top-level domains (eTLDs) with production-ready error handling
 
+use crate::constants::{AVERAGE_RULE_LEN, ETLD_GROUP_MAX};
 use std::sync::RwLock;
 
 /// ETLD manages all eTLDs in lists with thread-safety
@@ -11,7 +12,7 @@ use std::sync::RwLock;
 #[derive(Debug)]
 pub struct Etld {
     /// List of eTLD strings
-    list: RwLock<Vec<String>>,
+    list: RwLock<SuffixStore>,
     /// Number of dots in this eTLD level
     pub dots: usize,
 }
@@ -33,7 +34,7 @@ impl Etld {
     /// ```
     pub const fn new(dots: usize) -> Self {
         Self {
-            list: RwLock::new(Vec::new()),
+            list: RwLock::new(SuffixStore::new()),
             dots,
         }
     }
@@ -92,7 +93,7 @@ impl Etld {
         }
 
         let old_count = list.len();
-        list.push(s);
+        list.push(&s);
 
         if sort_list {
             list.sort();
@@ -169,9 +170,61 @@ impl Etld {
             return (String::new(), false);
         }
 
-        // Use map_or_else for more idiomatic Rust
-        list.binary_search(&search_str.to_string())
-            .map_or_else(|_| (String::new(), false), |idx| (list[idx].clone(), true))
+        // Compare as &str to avoid allocating the search key
+        list.binary_search(search_str).map_or_else(
+            || (String::new(), false),
+            |idx| (list.get(idx).to_string(), true),
+        )
+    }
+
+    /// Returns the entries that end with `.suffix`, in sorted order
+    ///
+    /// Sorting also builds an index of the entries ordered by their bytes read
+    /// from the end, in which every entry sharing trailing labels is adjacent.
+    /// The matching range is found with two binary searches rather than a scan
+    /// of the whole list.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - Trailing labels to match, without a leading dot, e.g. `uk`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal `RwLock` is poisoned due to a panic in another thread
+    /// while holding the read lock.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::etld::Etld;
+    ///
+    /// let etld = Etld::new(1);
+    /// for tld in ["org.uk", "co.jp", "co.uk"] {
+    ///     etld.add(tld.to_string(), false);
+    /// }
+    /// etld.sort();
+    ///
+    /// assert_eq!(etld.with_suffix("uk"), vec!["co.uk", "org.uk"]);
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// Entries added since the last `sort()` are not indexed yet, so until then
+    /// this falls back to a full scan.
+    pub fn with_suffix(&self, suffix: &str) -> Vec<String> {
+        let list = self.list.read().unwrap();
+        let dotted = format!(".{}", suffix);
+
+        let mut matches: Vec<String> = match list.suffix_range(&dotted) {
+            Some(range) => range.map(str::to_string).collect(),
+            None => list
+                .iter()
+                .filter(|entry| entry.ends_with(&dotted))
+                .map(str::to_string)
+                .collect(),
+        };
+        matches.sort();
+        matches
     }
 
     /// Returns a clone of the internal list for read-only access
@@ -201,7 +254,7 @@ impl Etld {
     /// This method clones the entire internal vector, which may be expensive
     /// for large lists. Use sparingly or consider alternatives for performance-critical code.
     pub fn get_list(&self) -> Vec<String> {
-        self.list.read().unwrap().clone()
+        self.list.read().unwrap().to_vec()
     }
 
     /// Checks if the list is empty
@@ -257,6 +310,16 @@ impl Etld {
         list.clear();
     }
 
+    /// Exchanges the entries of this list with those of `other`
+    ///
+    /// Both lists are locked for the exchange, so readers of either see all of
+    /// its old entries or all of its new ones.
+    pub(crate) fn swap(&self, other: &Etld) {
+        let mut list = self.list.write().unwrap();
+        let mut other_list = other.list.write().unwrap();
+        std::mem::swap(&mut *list, &mut *other_list);
+    }
+
     /// Returns an iterator over the eTLD entries (for advanced use cases)
     ///
     /// # Returns
@@ -285,6 +348,36 @@ impl Etld {
         self.get_list().into_iter()
     }
 
+    /// Calls `f` with each eTLD entry without cloning the list
+    ///
+    /// The read lock is held for the whole iteration, so `f` must not call
+    /// methods that write to this list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal `RwLock` is poisoned due to a panic in another thread
+    /// while holding the read lock.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::etld::Etld;
+    ///
+    /// let etld = Etld::new(0);
+    /// etld.add("com".to_string(), false);
+    /// etld.add("org".to_string(), false);
+    ///
+    /// let mut total_len = 0;
+    /// etld.for_each(|tld| total_len += tld.len());
+    /// assert_eq!(total_len, 6);
+    /// ```
+    pub fn for_each(&self, mut f: impl FnMut(&str)) {
+        let list = self.list.read().unwrap();
+        for entry in list.iter() {
+            f(entry);
+        }
+    }
+
     /// Returns the capacity of the internal vector
     ///
     /// # Returns
@@ -301,6 +394,9 @@ impl Etld {
 
     /// Reserves capacity for at least `additional` more elements
     ///
+    /// With the `intern` feature, room for their text is reserved as well,
+    /// estimated from `AVERAGE_RULE_LEN` for this list's dot count.
+    ///
     /// # Arguments
     ///
     /// * `additional` - The number of additional elements to reserve space for
@@ -310,8 +406,57 @@ impl Etld {
     /// Panics if the internal `RwLock` is poisoned due to a panic in another thread
     /// while holding the write lock, or if the new capacity overflows.
     pub fn reserve(&self, additional: usize) {
+        let average_len = AVERAGE_RULE_LEN[self.dots.min(ETLD_GROUP_MAX - 1)];
         let mut list = self.list.write().unwrap();
-        list.reserve(additional);
+        list.reserve(additional, additional * average_len);
+    }
+
+    /// Reserves capacity for `counts[dots]` more elements with each dot count
+    ///
+    /// For lists that hold rules of every depth, such as the private rule index.
+    pub(crate) fn reserve_by_dots(&self, counts: [usize; ETLD_GROUP_MAX]) {
+        let additional = counts.iter().sum();
+        let bytes = counts
+            .iter()
+            .zip(AVERAGE_RULE_LEN)
+            .map(|(count, average_len)| count * average_len)
+            .sum();
+        let mut list = self.list.write().unwrap();
+        list.reserve(additional, bytes);
+    }
+
+    /// Returns the capacity in bytes of the buffer the entries are packed into
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal `RwLock` is poisoned due to a panic in another thread
+    /// while holding the read lock.
+    #[cfg(all(test, feature = "intern"))]
+    pub(crate) fn buffer_capacity(&self) -> usize {
+        self.list.read().unwrap().buf.capacity()
+    }
+
+    /// Returns an estimate of the heap memory used by the stored eTLDs
+    ///
+    /// With the `intern` feature enabled all entries share a single buffer, which
+    /// avoids the per-string header and allocation overhead of the default storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal `RwLock` is poisoned due to a panic in another thread
+    /// while holding the read lock.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::etld::Etld;
+    ///
+    /// let etld = Etld::new(1);
+    /// etld.add("co.uk".to_string(), false);
+    /// assert!(etld.approximate_memory_bytes() >= "co.uk".len());
+    /// ```
+    pub fn approximate_memory_bytes(&self) -> usize {
+        self.list.read().unwrap().memory_bytes()
     }
 }
 
@@ -362,6 +507,215 @@ impl IntoIterator for &Etld {
     }
 }
 
+/// Backing storage for an eTLD list: one owned `String` per entry
+#[cfg(not(feature = "intern"))]
+#[derive(Debug, Clone)]
+struct SuffixStore {
+    entries: Vec<String>,
+    /// Entry indexes ordered by `cmp_reversed`, rebuilt by `sort`
+    by_suffix: Vec<u32>,
+}
+
+#[cfg(not(feature = "intern"))]
+impl SuffixStore {
+    const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            by_suffix: Vec::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn get(&self, idx: usize) -> &str {
+        &self.entries[idx]
+    }
+
+    fn push(&mut self, s: &str) {
+        self.entries.push(s.to_string());
+        self.by_suffix.clear();
+    }
+
+    fn sort(&mut self) {
+        self.entries.sort();
+        self.index_by_suffix();
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.by_suffix.clear();
+    }
+
+    fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    /// Each entry owns its text, so only the entries are reserved
+    fn reserve(&mut self, additional: usize, _bytes: usize) {
+        self.entries.reserve(additional);
+    }
+
+    fn memory_bytes(&self) -> usize {
+        self.entries.capacity() * std::mem::size_of::<String>()
+            + self.entries.iter().map(String::capacity).sum::<usize>()
+            + self.by_suffix.capacity() * std::mem::size_of::<u32>()
+    }
+}
+
+/// Backing storage for an eTLD list: every entry packed into one buffer
+///
+/// Entry `i` spans `buf[ends[i - 1]..ends[i]]`, so each entry costs four bytes
+/// of offset instead of a `String` header and its own allocation.
+#[cfg(feature = "intern")]
+#[derive(Debug, Clone)]
+struct SuffixStore {
+    buf: String,
+    ends: Vec<u32>,
+    /// Entry indexes ordered by `cmp_reversed`, rebuilt by `sort`
+    by_suffix: Vec<u32>,
+}
+
+#[cfg(feature = "intern")]
+impl SuffixStore {
+    const fn new() -> Self {
+        Self {
+            buf: String::new(),
+            ends: Vec::new(),
+            by_suffix: Vec::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    fn get(&self, idx: usize) -> &str {
+        let start = if idx == 0 {
+            0
+        } else {
+            self.ends[idx - 1] as usize
+        };
+        &self.buf[start..self.ends[idx] as usize]
+    }
+
+    fn push(&mut self, s: &str) {
+        self.buf.push_str(s);
+        // Suffix lists are bounded by the download limit, far below u32::MAX
+        self.ends.push(self.buf.len() as u32);
+        self.by_suffix.clear();
+    }
+
+    fn sort(&mut self) {
+        let mut entries: Vec<&str> = self.iter().collect();
+        entries.sort_unstable();
+
+        // Capacity reserved ahead of a parse is kept for the next one
+        let mut sorted = Self {
+            buf: String::with_capacity(self.buf.capacity()),
+            ends: Vec::with_capacity(self.ends.capacity()),
+            by_suffix: Vec::new(),
+        };
+        for entry in entries {
+            sorted.push(entry);
+        }
+        *self = sorted;
+        self.index_by_suffix();
+    }
+
+    fn clear(&mut self) {
+        self.buf.clear();
+        self.ends.clear();
+        self.by_suffix.clear();
+    }
+
+    fn capacity(&self) -> usize {
+        self.ends.capacity()
+    }
+
+    fn reserve(&mut self, additional: usize, bytes: usize) {
+        self.buf.reserve(bytes);
+        self.ends.reserve(additional);
+    }
+
+    fn memory_bytes(&self) -> usize {
+        self.buf.capacity()
+            + (self.ends.capacity() + self.by_suffix.capacity()) * std::mem::size_of::<u32>()
+    }
+}
+
+impl SuffixStore {
+    fn iter(&self) -> impl Iterator<Item = &str> {
+        (0..self.len()).map(|idx| self.get(idx))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn contains(&self, s: &str) -> bool {
+        self.iter().any(|entry| entry == s)
+    }
+
+    /// Binary searches a sorted store, returning the index of `s` if present
+    fn binary_search(&self, s: &str) -> Option<usize> {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.get(mid).cmp(s) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+
+    fn to_vec(&self) -> Vec<String> {
+        self.iter().map(str::to_string).collect()
+    }
+
+    /// Rebuilds the index of entries ordered by their bytes read from the end
+    fn index_by_suffix(&mut self) {
+        // Entry counts are bounded by the download limit, far below u32::MAX
+        let mut by_suffix: Vec<u32> = (0..self.len() as u32).collect();
+        by_suffix
+            .sort_unstable_by(|&a, &b| cmp_reversed(self.get(a as usize), self.get(b as usize)));
+        self.by_suffix = by_suffix;
+    }
+
+    /// Returns the entries ending with `tail`, or `None` if the index is stale
+    ///
+    /// In `cmp_reversed` order the entries ending with `tail` follow every
+    /// entry that sorts before `tail` itself, so both ends of the range are
+    /// found with `partition_point`.
+    fn suffix_range<'a>(&'a self, tail: &str) -> Option<impl Iterator<Item = &'a str>> {
+        if self.by_suffix.len() != self.len() {
+            return None;
+        }
+
+        let before = |idx: &u32| cmp_reversed(self.get(*idx as usize), tail).is_lt();
+        let start = self.by_suffix.partition_point(before);
+        let end = self
+            .by_suffix
+            .partition_point(|idx| before(idx) || self.get(*idx as usize).ends_with(tail));
+        Some(
+            self.by_suffix[start..end]
+                .iter()
+                .map(|&idx| self.get(idx as usize)),
+        )
+    }
+}
+
+/// Compares two entries by their bytes read from the end
+///
+/// Entries sharing trailing labels, such as `co.uk` and `org.uk`, are adjacent
+/// in this order.
+fn cmp_reversed(a: &str, b: &str) -> std::cmp::Ordering {
+    a.bytes().rev().cmp(b.bytes().rev())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,6 +731,7 @@ mod tests {
     #[test]
     fn test_const_new() {
         // Test that new() is indeed const
+        #[allow(clippy::declare_interior_mutable_const)]
         const ETLD: Etld = Etld::new(1);
         assert_eq!(ETLD.dots, 1);
     }
@@ -473,6 +828,59 @@ mod tests {
         assert_eq!(collected, vec!["com", "org"]);
     }
 
+    #[test]
+    fn test_for_each_matches_get_list() {
+        let etld = Etld::new(1);
+        for tld in ["co.uk", "com.au", "co.jp"] {
+            etld.add(tld.to_string(), false);
+        }
+        etld.sort();
+
+        let mut collected = Vec::new();
+        etld.for_each(|tld| collected.push(tld.to_string()));
+        assert_eq!(collected, etld.get_list());
+    }
+
+    #[test]
+    fn test_with_suffix_matches_full_scan() {
+        let etld = Etld::new(1);
+        for tld in [
+            "co.uk", "org.uk", "co.jp", "com.au", "x-uk.com", "uk.com", "ac.uk", "guk.net",
+            "co.nz", "muk", "ltd.uk",
+        ] {
+            etld.add(tld.to_string(), false);
+        }
+
+        let full_scan = |suffix: &str| {
+            let mut expected: Vec<String> = etld
+                .get_list()
+                .into_iter()
+                .filter(|entry| entry.ends_with(&format!(".{}", suffix)))
+                .collect();
+            expected.sort();
+            expected
+        };
+
+        // Unsorted lists are scanned, sorted ones use the index
+        assert_eq!(etld.with_suffix("uk"), full_scan("uk"));
+        etld.sort();
+        for suffix in [
+            "uk", "com", "jp", "au", "nz", "net", "k", "co.uk", "nope", "",
+        ] {
+            assert_eq!(etld.with_suffix(suffix), full_scan(suffix), "{:?}", suffix);
+        }
+        assert_eq!(
+            etld.with_suffix("uk"),
+            vec!["ac.uk", "co.uk", "ltd.uk", "org.uk"]
+        );
+
+        // Adding invalidates the index until the next sort
+        etld.add("gov.uk".to_string(), false);
+        assert_eq!(etld.with_suffix("uk"), full_scan("uk"));
+        etld.sort();
+        assert_eq!(etld.with_suffix("uk").len(), 5);
+    }
+
     #[test]
     fn test_capacity_and_reserve() {
         let etld = Etld::new(0);
@@ -506,6 +914,46 @@ mod tests {
         assert_eq!(found, "domain500.com");
     }
 
+    #[test]
+    fn test_lookups_after_compaction() {
+        let etld = Etld::new(1);
+        for i in (0..500).rev() {
+            etld.add(format!("co{}.uk", i), false);
+        }
+        etld.add("com.au".to_string(), false);
+        assert!(!etld.add("co7.uk".to_string(), false));
+        etld.sort();
+
+        assert_eq!(etld.count(), 501);
+        assert_eq!(etld.search("co0.uk"), ("co0.uk".to_string(), true));
+        assert_eq!(etld.search("co499.uk"), ("co499.uk".to_string(), true));
+        assert_eq!(etld.search("com.au"), ("com.au".to_string(), true));
+        assert!(!etld.search("co500.uk").1);
+        assert!(!etld.search("co").1);
+
+        let list = etld.get_list();
+        assert!(list.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_approximate_memory_bytes() {
+        let etld = Etld::new(1);
+        assert_eq!(etld.approximate_memory_bytes(), 0);
+
+        let entries: Vec<String> = (0..100).map(|i| format!("co{}.uk", i)).collect();
+        for entry in &entries {
+            etld.add(entry.clone(), false);
+        }
+        etld.sort();
+
+        let text_bytes: usize = entries.iter().map(String::len).sum();
+        let owned_bytes = text_bytes + entries.len() * std::mem::size_of::<String>();
+        assert!(etld.approximate_memory_bytes() >= text_bytes);
+        if cfg!(feature = "intern") {
+            assert!(etld.approximate_memory_bytes() < owned_bytes);
+        }
+    }
+
     #[test]
     fn test_concurrent_access() {
         use std::sync::Arc;
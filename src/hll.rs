@@ -0,0 +1,97 @@
+// file: src/hll.rs
+// description: HyperLogLog sketch for estimating the number of distinct strings in bounded memory
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Number of hash bits used to pick a register
+const PRECISION: u32 = 14;
+
+/// Number of registers, 16 KiB of memory for a standard error of about 0.8%
+const REGISTERS: usize = 1 << PRECISION;
+
+/// HyperLogLog cardinality sketch over 64-bit hashes
+///
+/// Each register keeps the longest run of leading zeros seen among the hashes
+/// routed to it, which is enough to estimate how many distinct values were
+/// added without storing them.
+pub(crate) struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Creates an empty sketch
+    pub(crate) fn new() -> Self {
+        Self {
+            registers: vec![0; REGISTERS],
+        }
+    }
+
+    /// Adds a value to the sketch
+    pub(crate) fn insert(&mut self, value: &str) {
+        // DefaultHasher::new uses fixed keys, so estimates are reproducible
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // A sentinel bit caps the rank when the remaining bits are all zero
+        let rest = (hash << PRECISION) | (1 << (PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    /// Estimates the number of distinct values added
+    pub(crate) fn estimate(&self) -> u64 {
+        let m = REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&rank| 2f64.powi(-i32::from(rank)))
+            .sum();
+        let estimate = alpha * m * m / sum;
+
+        // Small cardinalities leave registers empty; linear counting is more
+        // accurate there. 64-bit hashes need no large-range correction.
+        let empty = self.registers.iter().filter(|&&rank| rank == 0).count();
+        if estimate <= 2.5 * m && empty > 0 {
+            (m * (m / empty as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_sketch() {
+        assert_eq!(HyperLogLog::new().estimate(), 0);
+    }
+
+    #[test]
+    fn test_duplicates_are_counted_once() {
+        let mut sketch = HyperLogLog::new();
+        for _ in 0..1000 {
+            sketch.insert("example.com");
+        }
+        assert_eq!(sketch.estimate(), 1);
+    }
+
+    #[test]
+    fn test_estimate_within_error_bound() {
+        for count in [100usize, 10_000, 200_000] {
+            let mut sketch = HyperLogLog::new();
+            for i in 0..count {
+                sketch.insert(&format!("domain{}.com", i));
+            }
+            let error = (sketch.estimate() as f64 - count as f64).abs() / count as f64;
+            assert!(error < 0.03, "{} values, error {:.4}", count, error);
+        }
+    }
+}
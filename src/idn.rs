@@ -0,0 +1,249 @@
+// file: src/idn.rs
+// description: internationalized domain name helpers including script detection for homograph checks
+
+use std::borrow::Cow;
+
+/// Writing system a character belongs to, used for mixed-script detection
+///
+/// Only scripts commonly seen in domain names are distinguished; everything
+/// else is reported as `Other`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// Latin letters, including accented forms
+    Latin,
+    /// Greek letters
+    Greek,
+    /// Cyrillic letters
+    Cyrillic,
+    /// Armenian letters
+    Armenian,
+    /// Hebrew letters
+    Hebrew,
+    /// Arabic letters
+    Arabic,
+    /// Devanagari letters
+    Devanagari,
+    /// Thai letters
+    Thai,
+    /// Han ideographs, Hiragana, Katakana, Hangul and Bopomofo
+    ///
+    /// These are grouped because Chinese, Japanese and Korean names
+    /// legitimately combine them within a single label.
+    Cjk,
+    /// Any other letter
+    Other,
+}
+
+/// Returns the script of a character, or `None` for script-neutral characters
+///
+/// Digits, hyphens, dots and other ASCII punctuation are neutral and never
+/// contribute to mixing.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_tld::idn::{script_of, Script};
+///
+/// assert_eq!(script_of('a'), Some(Script::Latin));
+/// assert_eq!(script_of('а'), Some(Script::Cyrillic)); // U+0430
+/// assert_eq!(script_of('-'), None);
+/// ```
+pub fn script_of(c: char) -> Option<Script> {
+    if c.is_ascii() {
+        return if c.is_ascii_alphabetic() {
+            Some(Script::Latin)
+        } else {
+            None
+        };
+    }
+
+    if !c.is_alphabetic() {
+        return None;
+    }
+
+    let script = match c as u32 {
+        0x00C0..=0x024F | 0x1E00..=0x1EFF | 0x2C60..=0x2C7F | 0xA720..=0xA7FF => Script::Latin,
+        0x0370..=0x03FF | 0x1F00..=0x1FFF => Script::Greek,
+        0x0400..=0x052F | 0x1C80..=0x1C8F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => Script::Cyrillic,
+        0x0530..=0x058F => Script::Armenian,
+        0x0590..=0x05FF => Script::Hebrew,
+        0x0600..=0x06FF | 0x0750..=0x077F | 0x08A0..=0x08FF => Script::Arabic,
+        0x0900..=0x097F => Script::Devanagari,
+        0x0E00..=0x0E7F => Script::Thai,
+        0x1100..=0x11FF
+        | 0x3040..=0x30FF
+        | 0x3100..=0x312F
+        | 0x3130..=0x318F
+        | 0x31A0..=0x31FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xAC00..=0xD7AF
+        | 0xF900..=0xFAFF
+        | 0x20000..=0x3134F => Script::Cjk,
+        _ => Script::Other,
+    };
+
+    Some(script)
+}
+
+/// Checks whether a single label mixes characters from more than one script
+///
+/// Punycode (`xn--`) labels must be decoded before calling this function.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_tld::idn::is_mixed_script_label;
+///
+/// assert!(!is_mixed_script_label("example"));
+/// assert!(is_mixed_script_label("exаmple")); // Cyrillic 'а'
+/// ```
+pub fn is_mixed_script_label(label: &str) -> bool {
+    let mut scripts = label.chars().filter_map(script_of);
+    match scripts.next() {
+        Some(first) => scripts.any(|script| script != first),
+        None => false,
+    }
+}
+
+/// Decodes any punycode labels in a domain to Unicode
+///
+/// The `xn--` prefix is matched case-insensitively and decoded labels are
+/// lowercase. Labels that fail to decode are left as they were.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_tld::idn::to_unicode;
+///
+/// assert_eq!(to_unicode("xn--mnchen-3ya.de"), "münchen.de");
+/// assert_eq!(to_unicode("example.com"), "example.com");
+/// ```
+pub fn to_unicode(domain: &str) -> String {
+    domain
+        .split('.')
+        .map(|label| match label.get(..4) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("xn--") => {
+                let (decoded, result) = idna::domain_to_unicode(label);
+                if result.is_ok() {
+                    decoded
+                } else {
+                    label.to_string()
+                }
+            }
+            _ => label.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Cleans up characters that copy-pasting commonly adds to a URL
+///
+/// Control characters and invisible format characters such as zero-width
+/// spaces, bidi marks and byte order marks are removed. Fullwidth ASCII forms
+/// (`ｅｘａｍｐｌｅ．ｃｏｍ`) and the ideographic full stops `。` and `｡` are
+/// mapped to their ASCII equivalents. ASCII input without control characters
+/// is returned without copying.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_tld::idn::clean_pasted;
+///
+/// assert_eq!(clean_pasted("exa\u{200B}mple.com\n"), "example.com");
+/// assert_eq!(clean_pasted("ｅｘａｍｐｌｅ．ｃｏｍ"), "example.com");
+/// ```
+pub fn clean_pasted(input: &str) -> Cow<'_, str> {
+    if input.bytes().all(|b| b.is_ascii() && !b.is_ascii_control()) {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(
+        input
+            .chars()
+            .filter(|&c| !c.is_control() && !is_format_char(c))
+            .map(|c| match c {
+                '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+                '\u{3000}' => ' ',
+                '\u{3002}' | '\u{FF61}' => '.',
+                c => c,
+            })
+            .collect(),
+    )
+}
+
+/// Checks if a character is an invisible format character (Unicode category Cf)
+///
+/// Covers the format characters that show up in pasted text; rarer ones are
+/// left to URL validation.
+fn is_format_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{061C}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{206F}'
+            | '\u{FEFF}'
+            | '\u{FFF9}'..='\u{FFFB}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_of() {
+        assert_eq!(script_of('z'), Some(Script::Latin));
+        assert_eq!(script_of('ü'), Some(Script::Latin));
+        assert_eq!(script_of('α'), Some(Script::Greek));
+        assert_eq!(script_of('ж'), Some(Script::Cyrillic));
+        assert_eq!(script_of('日'), Some(Script::Cjk));
+        assert_eq!(script_of('テ'), Some(Script::Cjk));
+        assert_eq!(script_of('7'), None);
+        assert_eq!(script_of('-'), None);
+    }
+
+    #[test]
+    fn test_is_mixed_script_label() {
+        assert!(!is_mixed_script_label("example"));
+        assert!(!is_mixed_script_label("münchen"));
+        assert!(!is_mixed_script_label("пример"));
+        assert!(!is_mixed_script_label("例えテスト"));
+        assert!(!is_mixed_script_label("abc-123"));
+        assert!(!is_mixed_script_label(""));
+        assert!(is_mixed_script_label("pаypal")); // Cyrillic 'а'
+        assert!(is_mixed_script_label("gοogle")); // Greek 'ο'
+    }
+
+    #[test]
+    fn test_to_unicode() {
+        assert_eq!(to_unicode("xn--mnchen-3ya.de"), "münchen.de");
+        assert_eq!(to_unicode("xn--exmple-4nf.com"), "exаmple.com");
+        assert_eq!(to_unicode("xn--invalid-!!.com"), "xn--invalid-!!.com");
+        assert_eq!(to_unicode("XN--MNCHEN-3YA.de"), "münchen.de");
+        assert_eq!(to_unicode("Xn--mnchen-3ya.de"), "münchen.de");
+        assert_eq!(to_unicode("plain.example"), "plain.example");
+    }
+
+    #[test]
+    fn test_clean_pasted() {
+        assert!(matches!(
+            clean_pasted("https://example.com/"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            clean_pasted("\u{FEFF}https://exa\u{200B}mple.com\r\n"),
+            "https://example.com"
+        );
+        assert_eq!(
+            clean_pasted("ｗｗｗ．ＥＸＡＭＰＬＥ．ｃｏｍ"),
+            "www.EXAMPLE.com"
+        );
+        assert_eq!(clean_pasted("例え。テスト｡jp\u{200E}"), "例え.テスト.jp");
+        assert_eq!(clean_pasted("münchen.de"), "münchen.de");
+    }
+}
@@ -16,7 +16,7 @@
 //!
 //! ## Quick Start
 //!
-//! ```rust
+//! ```rust,no_run
 //! use rust_tld::{init, get_fqdn};
 //!
 //! #[tokio::main]
@@ -34,35 +34,51 @@
 //!
 //! ## Advanced Configuration
 //!
-//! ```rust
+//! ```rust,no_run
 //! use rust_tld::{init, Options};
 //! use std::time::Duration;
 //!
+//! # async fn run() -> Result<(), rust_tld::TldError> {
 //! let options = Options::new()
 //!     .allow_private_tlds(true)
 //!     .timeout(Duration::from_secs(30));
 //!
 //! init(Some(options)).await?;
+//! # Ok(())
+//! # }
 //! ```
 
 use std::sync::{Arc, OnceLock};
 use tokio::sync::RwLock;
 
+pub mod allowlist;
 pub mod constants;
 pub mod errors;
 pub mod etld;
 pub mod fqdn;
+#[cfg(feature = "hyperloglog")]
+mod hll;
+pub mod idn;
 pub mod options;
 
+pub use allowlist::{AllowList, FrozenAllowList, OriginValidator, OriginValidatorBuilder};
 pub use constants::*;
 pub use errors::TldError;
-pub use fqdn::Fqdn;
-pub use options::Options;
+pub use fqdn::{
+    registrable_changed, DomainLevels, Fqdn, HostClass, OriginClassification, ParseReport,
+    ParsedDomain, RuleKind, Scheme, SourceValidation, SuffixInfo, SuffixKind, Warning, WarningKind,
+    WildcardMatch,
+};
+pub use options::{
+    Callback, LoadedHook, MissResolver, MissResolverFn, Options, Preset, ProgressCallback,
+    SuffixPreference, WarningSink,
+};
 
 /// Trait defining the main interface for the TLD package
 ///
 /// This trait provides a common interface for FQDN extraction that can be
-/// implemented by different backend implementations.
+/// implemented by different backend implementations. It is object safe, so
+/// managers can be stored as `Arc<dyn FqdnManager + Send + Sync>`.
 pub trait FqdnManager {
     /// Extracts the FQDN from a URL
     ///
@@ -83,6 +99,12 @@ impl FqdnManager for Fqdn {
     }
 }
 
+impl From<Fqdn> for Arc<dyn FqdnManager + Send + Sync> {
+    fn from(fqdn: Fqdn) -> Self {
+        Arc::new(fqdn)
+    }
+}
+
 /// Global manager instance with thread-safe initialization
 static GLOBAL_MANAGER: OnceLock<Arc<RwLock<Option<Arc<Fqdn>>>>> = OnceLock::new();
 
@@ -104,7 +126,7 @@ static GLOBAL_MANAGER: OnceLock<Arc<RwLock<Option<Arc<Fqdn>>>>> = OnceLock::new(
 /// # Examples
 ///
 /// Basic initialization:
-/// ```rust
+/// ```rust,no_run
 /// use rust_tld::init;
 ///
 /// #[tokio::main]
@@ -115,7 +137,7 @@ static GLOBAL_MANAGER: OnceLock<Arc<RwLock<Option<Arc<Fqdn>>>>> = OnceLock::new(
 /// ```
 ///
 /// With custom options:
-/// ```rust
+/// ```rust,no_run
 /// use rust_tld::{init, Options};
 /// use std::time::Duration;
 ///
@@ -146,6 +168,57 @@ pub async fn init(opts: Option<Options>) -> Result<(), TldError> {
     Ok(())
 }
 
+/// Initialize the global TLD manager from a local file without a Tokio runtime
+///
+/// This is the runtime-free counterpart of `init` for purely synchronous
+/// applications. The file is read with `std::fs`, and once loaded,
+/// `get_fqdn_sync` and `validate_origin_sync` resolve against the global
+/// manager without needing a runtime either.
+///
+/// # Arguments
+///
+/// * `path` - Path to the local public suffix list file
+/// * `opts` - Optional configuration options; `public_suffix_file` is set to `path`
+///
+/// # Returns
+///
+/// * `Ok(())` - If initialization succeeds
+/// * `Err(TldError)` - If the file cannot be read or parsed
+///
+/// # Panics
+///
+/// This function will panic if called from within an async execution context.
+/// Use `init` with `Options::public_suffix_file` there instead.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rust_tld::{load_from_file_blocking, get_fqdn_sync};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     load_from_file_blocking("public_suffix_list.dat", None)?;
+///
+///     let fqdn = get_fqdn_sync("https://www.example.com")?;
+///     assert_eq!(fqdn, "example.com");
+///     Ok(())
+/// }
+/// ```
+///
+/// # Thread Safety
+///
+/// Like `init`, calls after the first successful initialization are no-ops.
+pub fn load_from_file_blocking(path: &str, opts: Option<Options>) -> Result<(), TldError> {
+    let manager_lock = GLOBAL_MANAGER.get_or_init(|| Arc::new(RwLock::new(None)));
+
+    let mut manager_guard = manager_lock.blocking_write();
+    if manager_guard.is_none() {
+        let fqdn = Fqdn::from_file_blocking(path, opts)?;
+        *manager_guard = Some(Arc::new(fqdn));
+    }
+
+    Ok(())
+}
+
 /// Get the global manager instance, initializing with defaults if needed
 async fn get_global_manager() -> Result<Arc<Fqdn>, TldError> {
     let manager_lock = GLOBAL_MANAGER.get_or_init(|| Arc::new(RwLock::new(None)));
@@ -158,17 +231,114 @@ async fn get_global_manager() -> Result<Arc<Fqdn>, TldError> {
     }
 
     // Need to initialize
-    init(None).await?;
+    auto_init().await?;
 
     let manager_guard = manager_lock.read().await;
     manager_guard
         .as_ref()
-        .map(|m| Arc::clone(m))
+        .map(Arc::clone)
         .ok_or(TldError::PublicSuffixDownload(
             "failed to initialize global manager".to_string(),
         ))
 }
 
+/// Initializes the global manager with default options on first use
+///
+/// With the `embedded` feature the list compiled into the binary is used, so
+/// no download is attempted; otherwise this behaves like `init(None)`.
+async fn auto_init() -> Result<(), TldError> {
+    let manager_lock = GLOBAL_MANAGER.get_or_init(|| Arc::new(RwLock::new(None)));
+
+    let mut manager_guard = manager_lock.write().await;
+    if manager_guard.is_none() {
+        #[cfg(feature = "embedded")]
+        let fqdn = Fqdn::from_embedded(None)?;
+        #[cfg(not(feature = "embedded"))]
+        let fqdn = Fqdn::new(None).await?;
+        *manager_guard = Some(Arc::new(fqdn));
+    }
+
+    Ok(())
+}
+
+/// Checks if the global manager has been initialized
+///
+/// Functions such as `get_fqdn` initialize it with defaults on first use, which
+/// downloads the Public Suffix List unless the `embedded` feature is enabled.
+/// This lets callers decide whether to call `init` explicitly first.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rust_tld::{init, is_initialized};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     if !is_initialized() {
+///         init(None).await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn is_initialized() -> bool {
+    try_global_manager().is_some()
+}
+
+/// Returns the global manager if it is already initialized, without blocking
+fn try_global_manager() -> Option<Arc<Fqdn>> {
+    let manager_lock = GLOBAL_MANAGER.get()?;
+    let manager_guard = manager_lock.try_read().ok()?;
+    manager_guard.as_ref().map(Arc::clone)
+}
+
+/// Build and data source information for support bundles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// Version of this crate at compile time
+    pub crate_version: &'static str,
+    /// Where the Public Suffix List is (or will be) loaded from
+    ///
+    /// Local files are reported as `file://` URLs.
+    pub psl_source_url: String,
+    /// Whether the Public Suffix List was loaded from data embedded in the binary
+    pub embedded: bool,
+}
+
+/// Returns the crate version and the Public Suffix List source in use
+///
+/// The source reflects the options of the global manager when it has been
+/// initialized, and the defaults otherwise. Before initialization, `embedded`
+/// reports whether first use would load the embedded list.
+///
+/// # Examples
+///
+/// ```rust
+/// use rust_tld::build_info;
+///
+/// let info = build_info();
+/// println!("rust-tld {} using {}", info.crate_version, info.psl_source_url);
+/// ```
+pub fn build_info() -> BuildInfo {
+    let manager = try_global_manager();
+    let embedded = manager
+        .as_ref()
+        .map_or(cfg!(feature = "embedded"), |manager| manager.loaded_embedded());
+    let options = manager
+        .map(|manager| manager.options.clone())
+        .unwrap_or_default();
+
+    let psl_source_url = match &options.public_suffix_file {
+        Some(file) => format!("file://{}", file),
+        None => options.public_suffix_url.clone(),
+    };
+
+    BuildInfo {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        psl_source_url,
+        embedded,
+    }
+}
+
 /// Extract the FQDN from a URL using the global manager
 ///
 /// This is the main function for extracting FQDNs from URLs. It handles various
@@ -185,7 +355,7 @@ async fn get_global_manager() -> Result<Arc<Fqdn>, TldError> {
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use rust_tld::{init, get_fqdn};
 ///
 /// #[tokio::main]
@@ -236,9 +406,12 @@ pub async fn get_fqdn(url: &str) -> Result<String, TldError> {
 /// * `true` - If the origin's FQDN matches one of the allowed origins
 /// * `false` - If the origin is invalid or not in the allowed list
 ///
+/// Origins whose host is itself a public suffix, such as `https://co.uk`,
+/// never validate, even if that suffix appears in `allowed_origins`.
+///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use rust_tld::{init, validate_origin};
 ///
 /// #[tokio::main]
@@ -268,7 +441,7 @@ pub async fn get_fqdn(url: &str) -> Result<String, TldError> {
 /// - Domain allowlist enforcement
 pub async fn validate_origin(origin: &str, allowed_origins: &[String]) -> bool {
     match get_fqdn(origin).await {
-        Ok(fqdn) => allowed_origins.iter().any(|allowed| fqdn == *allowed),
+        Ok(fqdn) => allowed_origins.contains(&fqdn),
         Err(_) => false,
     }
 }
@@ -289,12 +462,13 @@ pub async fn validate_origin(origin: &str, allowed_origins: &[String]) -> bool {
 ///
 /// # Panics
 ///
-/// This function will panic if called outside of a tokio runtime context.
+/// This function will panic if called outside of a tokio runtime context
+/// before the global manager is initialized (see `load_from_file_blocking`).
 /// Use the async version `get_fqdn` in async contexts.
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use rust_tld::{init, get_fqdn_sync};
 ///
 /// #[tokio::main]
@@ -314,6 +488,11 @@ pub async fn validate_origin(origin: &str, allowed_origins: &[String]) -> bool {
 /// This function blocks the current thread while the async operation completes.
 /// Prefer the async version when possible for better performance in async contexts.
 pub fn get_fqdn_sync(url: &str) -> Result<String, TldError> {
+    // Once initialized, resolving needs no runtime
+    if let Some(manager) = try_global_manager() {
+        return manager.get_fqdn(url);
+    }
+
     tokio::task::block_in_place(|| tokio::runtime::Handle::current().block_on(get_fqdn(url)))
 }
 
@@ -334,12 +513,13 @@ pub fn get_fqdn_sync(url: &str) -> Result<String, TldError> {
 ///
 /// # Panics
 ///
-/// This function will panic if called outside of a tokio runtime context.
+/// This function will panic if called outside of a tokio runtime context
+/// before the global manager is initialized (see `load_from_file_blocking`).
 /// Use the async version `validate_origin` in async contexts.
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use rust_tld::{init, validate_origin_sync};
 ///
 /// #[tokio::main]
@@ -354,6 +534,14 @@ pub fn get_fqdn_sync(url: &str) -> Result<String, TldError> {
 /// }
 /// ```
 pub fn validate_origin_sync(origin: &str, allowed_origins: &[String]) -> bool {
+    // Once initialized, resolving needs no runtime
+    if let Some(manager) = try_global_manager() {
+        return match manager.get_fqdn(origin) {
+            Ok(fqdn) => allowed_origins.contains(&fqdn),
+            Err(_) => false,
+        };
+    }
+
     tokio::task::block_in_place(|| {
         tokio::runtime::Handle::current().block_on(validate_origin(origin, allowed_origins))
     })
@@ -364,6 +552,7 @@ mod tests {
     use super::*;
 
     #[tokio::test]
+    #[ignore = "requires network access to download the public suffix list"]
     async fn test_init_and_get_fqdn() {
         // Test initialization
         assert!(init(None).await.is_ok());
@@ -378,30 +567,53 @@ mod tests {
     async fn test_validate_origin() {
         let allowed_origins = vec!["example.com".to_string(), "test.com".to_string()];
 
-        // This will return false due to lack of real data, but tests the API
+        // Without real data this returns false, but tests the API; the embedded
+        // list resolves the origin offline
         let result = validate_origin("https://www.example.com", &allowed_origins).await;
-        assert!(!result); // Expected to be false without real public suffix data
+        assert_eq!(result, cfg!(feature = "embedded"));
+    }
+
+    #[test]
+    fn test_build_info() {
+        let info = build_info();
+
+        // Compare against the version declared in the package manifest
+        let manifest_version = include_str!("../Cargo.toml")
+            .lines()
+            .find_map(|line| line.strip_prefix("version = "))
+            .map(|version| version.trim_matches('"'))
+            .unwrap();
+        assert_eq!(info.crate_version, manifest_version);
+        assert_eq!(info.crate_version, env!("CARGO_PKG_VERSION"));
+        assert!(!info.psl_source_url.is_empty());
+        assert_eq!(info.embedded, cfg!(feature = "embedded"));
     }
 
     #[test]
     #[should_panic]
+    #[cfg_attr(
+        feature = "embedded",
+        ignore = "another test may already have loaded the embedded list"
+    )]
     fn test_sync_functions_outside_runtime() {
         // This should panic when called outside tokio runtime
         let _ = get_fqdn_sync("https://example.com");
     }
 
-    #[tokio::test]
+    #[tokio::test(flavor = "multi_thread")]
     async fn test_sync_functions_in_runtime() {
         // These should work when called within tokio runtime
+        // Without real data these fail; the embedded list resolves them
         let result = get_fqdn_sync("https://example.com");
-        assert!(result.is_err()); // Expected error without real data
+        assert_eq!(result.is_ok(), cfg!(feature = "embedded"));
 
         let allowed = vec!["example.com".to_string()];
         let validation = validate_origin_sync("https://example.com", &allowed);
-        assert!(!validation); // Expected false without real data
+        assert_eq!(validation, cfg!(feature = "embedded"));
     }
 
     #[tokio::test]
+    #[ignore = "requires network access to download the public suffix list"]
     async fn test_multiple_init_calls() {
         // Test that multiple init calls don't cause issues
         assert!(init(None).await.is_ok());
@@ -410,6 +622,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "requires network access to download the public suffix list"]
     async fn test_global_manager_thread_safety() {
         use tokio::task::JoinSet;
 
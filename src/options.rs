@@ -1,11 +1,105 @@
 // file: src/options.rs
 // description: defines options for the FQDN manager
 
-use crate::constants::PUBLIC_SUFFIX_FILE_URL;
+use crate::constants::{
+    ACCEPTABLE_CONTENT_TYPES, MAX_DOWNLOAD_SIZE, MAX_TIMEOUT, MIN_TIMEOUT, PUBLIC_SUFFIX_FILE_URL,
+};
+use crate::errors::TldError;
+use crate::fqdn::{Fqdn, SuffixKind, Warning};
 use reqwest::Client;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 use std::time::Duration;
 
+/// A shared closure stored in `Options`, such as a hook or a callback
+///
+/// Closures cannot be compared, so two callbacks are equal, and hash alike,
+/// only when they share the same `Arc`.
+pub struct Callback<F: ?Sized>(pub Arc<F>);
+
+impl<F: ?Sized> Clone for Callback<F> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for Callback<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Callback(..)")
+    }
+}
+
+impl<F: ?Sized> PartialEq for Callback<F> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::addr_eq(Arc::as_ptr(&self.0), Arc::as_ptr(&other.0))
+    }
+}
+
+impl<F: ?Sized> Eq for Callback<F> {}
+
+impl<F: ?Sized> Hash for Callback<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as *const ()).hash(state);
+    }
+}
+
+/// Callback invoked with `(bytes_so_far, total_if_known)` while downloading
+pub type ProgressCallback = Callback<dyn Fn(u64, Option<u64>) + Send + Sync>;
+
+/// Hook invoked with the manager after a public suffix list is loaded
+pub type LoadedHook = Callback<dyn Fn(&Fqdn) + Send + Sync>;
+
+/// Sink receiving each rule dropped while parsing a public suffix list
+pub type WarningSink = Callback<dyn Fn(Warning) + Send + Sync>;
+
+/// Function from a host that no rule matches to its public suffix
+pub type MissResolverFn = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+/// Resolver asked for the public suffix of a host that no rule matches
+pub type MissResolver = Callback<MissResolverFn>;
+
+/// Preset groups of options for common scenarios
+///
+/// Every mode of the manager requires the Public Suffix List markers and
+/// rejects hosts without a listed suffix; presets only choose between the
+/// options below. There is no option to assume a single-label suffix for
+/// unlisted hosts, so `Lenient` cannot set one; use `miss_resolver` for that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// Never download; the list comes from `public_suffix_file`, or from the
+    /// binary with the `embedded` feature
+    Offline,
+    /// ICANN suffixes only, downloaded without following redirects
+    Strict,
+    /// ICANN and private suffixes, downloaded with the default redirect limit
+    Lenient,
+}
+
+/// Which public suffix rule wins when both ICANN and private rules match a host
+///
+/// Only makes a difference when `allow_private_tlds` is enabled, since private
+/// rules are not loaded otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SuffixPreference {
+    /// The longest matching rule wins, whichever section it comes from
+    #[default]
+    LongestMatch,
+    /// Private rules are ignored and only ICANN rules are matched
+    IcannOnly,
+    /// A matching private rule wins even if an ICANN rule is as long or longer
+    PrivatePreferred,
+}
+
 /// Options for the FQDN Manager
+///
+/// Options can key a cache of managers: equality and hashing cover every field
+/// except `custom_http_client`, which is ignored because clients cannot be
+/// compared. Callbacks and hooks are equal only when they share the same `Arc`.
+/// Clippy's `mutable_key_type` lint still flags such maps because of the
+/// client, and can be allowed for them.
 #[derive(Debug, Clone)]
 pub struct Options {
     /// Determines whether private TLDs are allowed
@@ -22,6 +116,75 @@ pub struct Options {
 
     /// Local file path containing the public suffix list
     pub public_suffix_file: Option<String>,
+
+    /// Base64-encoded public suffix list, used instead of downloading
+    pub public_suffix_base64: Option<String>,
+
+    /// Maximum number of redirects to follow when downloading (0 disallows redirects)
+    pub max_redirects: usize,
+
+    /// Maximum accepted size of the downloaded public suffix list in bytes
+    pub max_download_bytes: usize,
+
+    /// Number of times a failed download is retried (0 makes a single attempt)
+    pub max_retries: usize,
+
+    /// Content types accepted for the download, matched as substrings of the header
+    pub acceptable_content_types: Vec<String>,
+
+    /// Extra URL schemes (e.g. `myapp`) recognized in addition to the built-in ones
+    pub additional_schemes: Vec<String>,
+
+    /// Drops a non-numeric segment after `host:` instead of rejecting the URL
+    pub lenient_ports: bool,
+
+    /// Treats hosts as ASCII or punycode and skips IDN decoding during lookups
+    pub assume_ascii: bool,
+
+    /// Rejects hosts with punycode labels that are not valid IDNA
+    pub strict_idn: bool,
+
+    /// Rejects hosts under private suffixes in `get_fqdn` and origin validation
+    pub deny_private_suffix_results: bool,
+
+    /// Treats special-use names such as `localhost` and `test` as public suffixes
+    pub special_use_tlds: bool,
+
+    /// Disables downloading; a local list or the `embedded` feature must be provided
+    pub offline: bool,
+
+    /// Directory of extra rule files merged after the public suffix list
+    pub supplemental_dir: Option<String>,
+
+    /// Called as the public suffix list downloads, for progress reporting
+    pub progress_callback: Option<ProgressCallback>,
+
+    /// Called after each successful load of the public suffix list, for verification
+    pub on_loaded: Option<LoadedHook>,
+
+    /// Called with each rule dropped while parsing the public suffix list
+    pub warning_sink: Option<WarningSink>,
+
+    /// Consulted for the suffix of a host that matches no rule, e.g. a new TLD
+    pub miss_resolver: Option<MissResolver>,
+
+    /// Remembers suffixes returned by `miss_resolver` until the list is reloaded
+    pub cache_resolved_misses: bool,
+
+    /// Shares one allocation between identical domains from `get_fqdn_arc`
+    pub intern_domains: bool,
+
+    /// Appends the DNS root dot to returned domains and suffixes, e.g. `example.com.`
+    pub trailing_dot_output: bool,
+
+    /// How to choose between matching ICANN and private suffix rules
+    pub suffix_preference: SuffixPreference,
+
+    /// Suffixes that take precedence over every rule in the loaded list
+    pub override_suffixes: HashMap<String, SuffixKind>,
+
+    /// Includes the offending input host in URL and TLD errors
+    pub verbose_errors: bool,
 }
 
 impl Options {
@@ -30,6 +193,215 @@ impl Options {
         Self::default()
     }
 
+    /// Applies a preset group of options
+    ///
+    /// The preset sets `offline`, `allow_private_tlds`, `max_redirects` and
+    /// `lenient_ports`; other options are left as they are and can still be
+    /// changed afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::{Options, Preset};
+    ///
+    /// let options = Options::new()
+    ///     .preset(Preset::Offline)
+    ///     .public_suffix_file("/path/to/public_suffix_list.dat");
+    /// assert!(options.offline);
+    /// ```
+    pub fn preset(mut self, preset: Preset) -> Self {
+        match preset {
+            Preset::Offline => {
+                self.offline = true;
+                self.allow_private_tlds = false;
+                self.max_redirects = 0;
+                self.lenient_ports = false;
+            }
+            Preset::Strict => {
+                self.offline = false;
+                self.allow_private_tlds = false;
+                self.max_redirects = 0;
+                self.lenient_ports = false;
+            }
+            Preset::Lenient => {
+                self.offline = false;
+                self.allow_private_tlds = true;
+                self.max_redirects = Self::default().max_redirects;
+                self.lenient_ports = true;
+            }
+        }
+        self
+    }
+
+    /// Reads options from `RUST_TLD_*` environment variables
+    ///
+    /// Intended for containerized deployments. Unset or empty variables keep
+    /// their defaults, and the result is checked with `validate`.
+    ///
+    /// | Variable | Option | Format |
+    /// |----------|--------|--------|
+    /// | `RUST_TLD_PRIVATE` | `allow_private_tlds` | boolean |
+    /// | `RUST_TLD_TIMEOUT` | `timeout` | seconds, e.g. `30` or `2.5` |
+    /// | `RUST_TLD_URL` | `public_suffix_url` | URL |
+    /// | `RUST_TLD_FILE` | `public_suffix_file` | path |
+    /// | `RUST_TLD_BASE64` | `public_suffix_base64` | base64 list |
+    /// | `RUST_TLD_OFFLINE` | `offline` | boolean |
+    /// | `RUST_TLD_SUPPLEMENTAL_DIR` | `supplemental_dir` | path |
+    /// | `RUST_TLD_MAX_REDIRECTS` | `max_redirects` | integer |
+    /// | `RUST_TLD_MAX_RETRIES` | `max_retries` | integer |
+    /// | `RUST_TLD_MAX_DOWNLOAD_BYTES` | `max_download_bytes` | integer |
+    /// | `RUST_TLD_SCHEMES` | `additional_schemes` | comma-separated list |
+    /// | `RUST_TLD_SPECIAL_USE` | `special_use_tlds` | boolean |
+    /// | `RUST_TLD_VERBOSE_ERRORS` | `verbose_errors` | boolean |
+    ///
+    /// Booleans are `1`, `true`, `yes` or `on` and `0`, `false`, `no` or `off`,
+    /// in any case.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Options)` - The defaults overridden by the variables that are set
+    /// * `Err(TldError::InvalidOptions)` - Naming the first malformed variable,
+    ///   or if the resulting options fail `validate`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rust_tld::Options;
+    ///
+    /// // RUST_TLD_OFFLINE=1 RUST_TLD_FILE=/etc/psl/public_suffix_list.dat
+    /// let options = Options::from_env().expect("invalid RUST_TLD_* variable");
+    /// ```
+    pub fn from_env() -> Result<Self, TldError> {
+        Self::from_vars(|name| std::env::var_os(name))
+    }
+
+    /// Builds options from variables looked up by name, as in `from_env`
+    fn from_vars<F>(lookup: F) -> Result<Self, TldError>
+    where
+        F: Fn(&str) -> Option<OsString>,
+    {
+        let var = |name: &str| -> Result<Option<String>, TldError> {
+            match lookup(name) {
+                None => Ok(None),
+                Some(value) => match value.into_string() {
+                    Ok(value) if value.trim().is_empty() => Ok(None),
+                    Ok(value) => Ok(Some(value.trim().to_string())),
+                    Err(_) => Err(TldError::InvalidOptions(format!(
+                        "{} is not valid UTF-8",
+                        name
+                    ))),
+                },
+            }
+        };
+        let malformed = |name: &str, value: &str, expected: &str| {
+            TldError::InvalidOptions(format!("{} must be {}, got {:?}", name, expected, value))
+        };
+        let flag = |name: &str| -> Result<Option<bool>, TldError> {
+            var(name)?
+                .map(|value| match value.to_ascii_lowercase().as_str() {
+                    "1" | "true" | "yes" | "on" => Ok(true),
+                    "0" | "false" | "no" | "off" => Ok(false),
+                    _ => Err(malformed(name, &value, "a boolean")),
+                })
+                .transpose()
+        };
+        let count = |name: &str| -> Result<Option<usize>, TldError> {
+            var(name)?
+                .map(|value| {
+                    value
+                        .parse()
+                        .map_err(|_| malformed(name, &value, "a non-negative integer"))
+                })
+                .transpose()
+        };
+
+        let mut options = Self::default();
+        if let Some(allow) = flag("RUST_TLD_PRIVATE")? {
+            options.allow_private_tlds = allow;
+        }
+        if let Some(value) = var("RUST_TLD_TIMEOUT")? {
+            options.timeout = value
+                .parse()
+                .ok()
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                .ok_or_else(|| malformed("RUST_TLD_TIMEOUT", &value, "a number of seconds"))?;
+        }
+        if let Some(url) = var("RUST_TLD_URL")? {
+            options.public_suffix_url = url;
+        }
+        options.public_suffix_file = var("RUST_TLD_FILE")?;
+        options.public_suffix_base64 = var("RUST_TLD_BASE64")?;
+        if let Some(offline) = flag("RUST_TLD_OFFLINE")? {
+            options.offline = offline;
+        }
+        options.supplemental_dir = var("RUST_TLD_SUPPLEMENTAL_DIR")?;
+        if let Some(max) = count("RUST_TLD_MAX_REDIRECTS")? {
+            options.max_redirects = max;
+        }
+        if let Some(retries) = count("RUST_TLD_MAX_RETRIES")? {
+            options.max_retries = retries;
+        }
+        if let Some(max) = count("RUST_TLD_MAX_DOWNLOAD_BYTES")? {
+            options.max_download_bytes = max;
+        }
+        if let Some(schemes) = var("RUST_TLD_SCHEMES")? {
+            options = options.additional_schemes(
+                schemes
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|scheme| !scheme.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            );
+        }
+        if let Some(enabled) = flag("RUST_TLD_SPECIAL_USE")? {
+            options.special_use_tlds = enabled;
+        }
+        if let Some(verbose) = flag("RUST_TLD_VERBOSE_ERRORS")? {
+            options.verbose_errors = verbose;
+        }
+
+        options.validate()?;
+        Ok(options)
+    }
+
+    /// Checks that the options are within sane ranges
+    ///
+    /// Called when a manager is created, so misconfiguration surfaces as a
+    /// clear error instead of, for example, every download timing out at once.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If all options are valid
+    /// * `Err(TldError::InvalidOptions)` - If the timeout is outside
+    ///   `MIN_TIMEOUT..=MAX_TIMEOUT`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::Options;
+    /// use std::time::Duration;
+    ///
+    /// assert!(Options::new().validate().is_ok());
+    /// assert!(Options::new().timeout(Duration::ZERO).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), TldError> {
+        if self.timeout < MIN_TIMEOUT {
+            return Err(TldError::InvalidOptions(format!(
+                "timeout of {:?} is shorter than the minimum of {:?}",
+                self.timeout, MIN_TIMEOUT
+            )));
+        }
+        if self.timeout > MAX_TIMEOUT {
+            return Err(TldError::InvalidOptions(format!(
+                "timeout of {:?} is longer than the maximum of {:?}",
+                self.timeout, MAX_TIMEOUT
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Sets whether private TLDs are allowed
     pub fn allow_private_tlds(mut self, allow: bool) -> Self {
         self.allow_private_tlds = allow;
@@ -59,6 +431,366 @@ impl Options {
         self.public_suffix_file = Some(file.into());
         self
     }
+
+    /// Sets an inline base64-encoded public suffix list
+    ///
+    /// Intended for deployments that pass configuration through environment
+    /// variables. The list is decoded and parsed without touching the network
+    /// or filesystem; a `public_suffix_file`, if also set, takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rust_tld::Options;
+    ///
+    /// let encoded = std::env::var("PUBLIC_SUFFIX_LIST_B64").unwrap();
+    /// let options = Options::new().offline(true).public_suffix_base64(encoded);
+    /// ```
+    pub fn public_suffix_base64<S: Into<String>>(mut self, encoded: S) -> Self {
+        self.public_suffix_base64 = Some(encoded.into());
+        self
+    }
+
+    /// Sets the maximum number of redirects followed during download
+    ///
+    /// A value of 0 rejects any redirect. Ignored when a custom HTTP client is set.
+    pub fn max_redirects(mut self, max: usize) -> Self {
+        self.max_redirects = max;
+        self
+    }
+
+    /// Sets how many times a failed download is retried
+    ///
+    /// Retries wait 1s, then 2s, doubling up to 32s between attempts. A value
+    /// of 0 makes a single attempt and returns its error immediately, for
+    /// deployments that fail fast and retry elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::Options;
+    ///
+    /// let options = Options::new().max_retries(0);
+    /// assert_eq!(options.max_retries, 0);
+    /// ```
+    pub fn max_retries(mut self, retries: usize) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Sets the maximum accepted download size in bytes
+    pub fn max_download_bytes(mut self, max: usize) -> Self {
+        self.max_download_bytes = max;
+        self
+    }
+
+    /// Sets the content types accepted for the downloaded list
+    ///
+    /// A response is accepted if its `Content-Type` header contains any of the
+    /// entries, ignoring case, or if it has no `Content-Type` header at all. The
+    /// default is `ACCEPTABLE_CONTENT_TYPES`. An empty list rejects every response
+    /// that declares a content type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::Options;
+    ///
+    /// let options = Options::new().acceptable_content_types(vec![
+    ///     "text/".to_string(),
+    ///     "application/x-psl".to_string(),
+    /// ]);
+    /// assert_eq!(options.acceptable_content_types.len(), 2);
+    /// ```
+    pub fn acceptable_content_types(mut self, content_types: Vec<String>) -> Self {
+        self.acceptable_content_types = content_types
+            .into_iter()
+            .map(|content_type| content_type.trim().to_lowercase())
+            .filter(|content_type| !content_type.is_empty())
+            .collect();
+        self
+    }
+
+    /// Sets whether downloading is disabled
+    ///
+    /// When offline, `Fqdn::new` loads a local public suffix file or base64
+    /// list if one is set, then falls back to the list embedded with the
+    /// `embedded` feature; without either, loading fails.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Sets how to choose between matching ICANN and private suffix rules
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::{Options, SuffixPreference};
+    ///
+    /// let options = Options::new()
+    ///     .allow_private_tlds(true)
+    ///     .suffix_preference(SuffixPreference::IcannOnly);
+    /// assert_eq!(options.suffix_preference, SuffixPreference::IcannOnly);
+    /// ```
+    pub fn suffix_preference(mut self, preference: SuffixPreference) -> Self {
+        self.suffix_preference = preference;
+        self
+    }
+
+    /// Sets suffixes that take precedence over the public suffix list
+    ///
+    /// Useful for testing a TLD before it is published in the list. A host
+    /// under an override resolves to the override plus one label, even if the
+    /// list has a longer, wildcard or exception rule for it. The kind is
+    /// reported by `suffix_info` and decides whether
+    /// `deny_private_suffix_results` applies. Suffixes are lowercased and
+    /// stripped of surrounding dots.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::{Options, SuffixKind};
+    /// use std::collections::HashMap;
+    ///
+    /// let options = Options::new()
+    ///     .override_suffixes(HashMap::from([("New.TLD.".to_string(), SuffixKind::Icann)]));
+    /// assert_eq!(options.override_suffixes.get("new.tld"), Some(&SuffixKind::Icann));
+    /// ```
+    pub fn override_suffixes(mut self, suffixes: HashMap<String, SuffixKind>) -> Self {
+        self.override_suffixes = suffixes
+            .into_iter()
+            .map(|(suffix, kind)| (suffix.trim().trim_matches('.').to_lowercase(), kind))
+            .filter(|(suffix, _)| !suffix.is_empty())
+            .collect();
+        self
+    }
+
+    /// Sets a callback to observe download progress
+    ///
+    /// The callback receives the bytes read so far and the total size when the
+    /// server sent a `Content-Length`. It is called once per received chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::Options;
+    /// use std::sync::Arc;
+    ///
+    /// let options = Options::new().progress_callback(Arc::new(|read, total| {
+    ///     match total {
+    ///         Some(total) => println!("{}/{} bytes", read, total),
+    ///         None => println!("{} bytes", read),
+    ///     }
+    /// }));
+    /// assert!(options.progress_callback.is_some());
+    /// ```
+    pub fn progress_callback(
+        mut self,
+        callback: Arc<dyn Fn(u64, Option<u64>) + Send + Sync>,
+    ) -> Self {
+        self.progress_callback = Some(Callback(callback));
+        self
+    }
+
+    /// Sets a hook that runs after the public suffix list is loaded
+    ///
+    /// The hook receives the manager once the list has been parsed and indexed,
+    /// so it can check for required suffixes or record `loaded_sha256`. It runs
+    /// on every successful load, including reloads, and never after a failed
+    /// one. Supplemental rules are merged after it runs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::Options;
+    /// use std::sync::Arc;
+    ///
+    /// let options = Options::new().on_loaded(Arc::new(|fqdn| {
+    ///     assert!(fqdn.count_for_dots(1) > 0, "no two-label suffixes loaded");
+    /// }));
+    /// assert!(options.on_loaded.is_some());
+    /// ```
+    pub fn on_loaded(mut self, hook: Arc<dyn Fn(&Fqdn) + Send + Sync>) -> Self {
+        self.on_loaded = Some(Callback(hook));
+        self
+    }
+
+    /// Sets a sink that receives a `Warning` for each rule dropped while parsing
+    ///
+    /// Rules that are too deep, use an unsupported wildcard or are malformed
+    /// are otherwise only counted in `Fqdn::last_parse_report`. The sink is
+    /// called as each line is read, whether or not the `logging` feature is
+    /// enabled, and also for lists that go on to fail validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::Options;
+    /// use std::sync::Arc;
+    ///
+    /// let options = Options::new().warning_sink(Arc::new(|warning| {
+    ///     eprintln!("public suffix list: {}", warning);
+    /// }));
+    /// assert!(options.warning_sink.is_some());
+    /// ```
+    pub fn warning_sink(mut self, sink: Arc<dyn Fn(Warning) + Send + Sync>) -> Self {
+        self.warning_sink = Some(Callback(sink));
+        self
+    }
+
+    /// Sets a resolver consulted when no rule matches a host
+    ///
+    /// The resolver receives the lowercased host and returns its public
+    /// suffix, for experimental or very new TLDs missing from the loaded list.
+    /// It is only called after the list, `override_suffixes` and special-use
+    /// names have all missed. A returned suffix that does not end the host on
+    /// a label boundary is ignored, as is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rust_tld::Options;
+    /// use std::sync::Arc;
+    ///
+    /// let options = Options::new().miss_resolver(Arc::new(|host| {
+    ///     host.ends_with(".newtld").then(|| "newtld".to_string())
+    /// }));
+    /// assert!(options.miss_resolver.is_some());
+    /// ```
+    pub fn miss_resolver(mut self, resolver: Arc<MissResolverFn>) -> Self {
+        self.miss_resolver = Some(Callback(resolver));
+        self
+    }
+
+    /// Sets whether suffixes returned by `miss_resolver` are cached
+    ///
+    /// When enabled, a suffix the resolver returned answers later hosts under
+    /// it without calling the resolver again. The cache is shared by clones of
+    /// the manager and emptied whenever a new list is loaded. `None` results
+    /// are never cached.
+    pub fn cache_resolved_misses(mut self, enabled: bool) -> Self {
+        self.cache_resolved_misses = enabled;
+        self
+    }
+
+    /// Sets whether `get_fqdn_arc` interns the domains it returns
+    ///
+    /// When enabled, lookups that resolve to the same registrable domain
+    /// return clones of one `Arc<str>`. The interned set is shared by clones
+    /// of the manager and keeps every distinct domain it has returned.
+    pub fn intern_domains(mut self, enabled: bool) -> Self {
+        self.intern_domains = enabled;
+        self
+    }
+
+    /// Sets whether returned names end with the DNS root dot
+    ///
+    /// When enabled, every method that returns a registrable domain or public
+    /// suffix gives the fully qualified form DNS tooling expects, e.g.
+    /// `example.com.` and `co.uk.`, including the names inside `SuffixInfo` and
+    /// `DomainLevels`. The parts of `parse` stay aligned with its `host` and are
+    /// left as they are. Input hosts may have a trailing dot either way.
+    pub fn trailing_dot_output(mut self, enabled: bool) -> Self {
+        self.trailing_dot_output = enabled;
+        self
+    }
+
+    /// Sets whether special-use names resolve as public suffixes
+    ///
+    /// When set, the names in `SPECIAL_USE_TLDS`, such as `localhost` and
+    /// `test`, act as ICANN suffixes, so `app.foo.localhost` resolves to
+    /// `foo.localhost` instead of failing with an unknown TLD. A longer rule
+    /// from the list still wins, and `home.arpa` wins over the listed `arpa`.
+    pub fn special_use_tlds(mut self, enabled: bool) -> Self {
+        self.special_use_tlds = enabled;
+        self
+    }
+
+    /// Sets how a non-numeric segment after `host:` is handled
+    ///
+    /// A numeric segment after the host, as in `example.com:8080`, is always a
+    /// port and is stripped. Anything else, as in `example.com:abc`, is rejected
+    /// with an `"invalid port"` error by default; when lenient, it is dropped
+    /// together with the rest of the authority and the host is used as-is.
+    pub fn lenient_ports(mut self, lenient: bool) -> Self {
+        self.lenient_ports = lenient;
+        self
+    }
+
+    /// Sets whether `get_fqdn` errors include the offending input host
+    ///
+    /// Errors are terse by default so that logged errors cannot leak hosts
+    /// that identify users. When enabled, `InvalidUrl` and `InvalidTld` errors
+    /// carry the host as given (case preserved, without userinfo), escaped and
+    /// capped at `MAX_ERROR_HOST_LEN` characters.
+    pub fn verbose_errors(mut self, verbose: bool) -> Self {
+        self.verbose_errors = verbose;
+        self
+    }
+
+    /// Sets a directory of supplemental suffix files to merge on load
+    ///
+    /// Every load of the public suffix list, including reloads, reads every
+    /// file in the directory in filename order and merges its rules after
+    /// the list. Subdirectories are ignored. Each file holds rules in the
+    /// public suffix list format but needs no section markers or minimum
+    /// size. The rules are always applied and are reported as private, since
+    /// they are not part of the ICANN section. An error in any file fails the
+    /// load and leaves the loaded rules unchanged.
+    pub fn supplemental_dir<S: Into<String>>(mut self, dir: S) -> Self {
+        self.supplemental_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets whether hosts are assumed to be ASCII or punycode already
+    ///
+    /// By default punycode labels are decoded so that they match the Unicode
+    /// rules of the public suffix list. Callers whose hosts are known to be
+    /// ASCII, and whose list holds the punycode form of the rules they need,
+    /// can skip that work. Hosts are then only ASCII-lowercased, so Unicode
+    /// input is matched literally, and punycode rules are not also stored in
+    /// their Unicode form. Set it before the list is loaded.
+    pub fn assume_ascii(mut self, assume: bool) -> Self {
+        self.assume_ascii = assume;
+        self
+    }
+
+    /// Sets whether punycode labels are validated strictly
+    ///
+    /// By default a label starting with `xn--` that does not decode is matched
+    /// as plain ASCII. When strict, every such label must be valid RFC 3492
+    /// punycode that decodes to a valid IDNA label, checked with the `idna`
+    /// crate's strict mode, or the host is rejected with an
+    /// `"invalid punycode label"` error.
+    pub fn strict_idn(mut self, strict: bool) -> Self {
+        self.strict_idn = strict;
+        self
+    }
+
+    /// Sets whether `get_fqdn` rejects hosts under private suffixes
+    ///
+    /// With private rules loaded for correct results, security contexts can use
+    /// this to refuse hosts such as `user.github.io` with an `InvalidTld` error,
+    /// which also makes origin validation fail for them. Domains registered
+    /// under ICANN suffixes, such as `amazonaws.com`, still resolve. Has no effect
+    /// unless `allow_private_tlds` is set, since private rules are not loaded.
+    pub fn deny_private_suffix_results(mut self, deny: bool) -> Self {
+        self.deny_private_suffix_results = deny;
+        self
+    }
+
+    /// Sets extra URL schemes to recognize and strip, such as `myapp` for `myapp://`
+    ///
+    /// Schemes are lowercased and may be given with or without the trailing `://`.
+    pub fn additional_schemes(mut self, schemes: Vec<String>) -> Self {
+        self.additional_schemes = schemes
+            .into_iter()
+            .map(|scheme| scheme.trim_end_matches("://").to_lowercase())
+            .filter(|scheme| !scheme.is_empty())
+            .collect();
+        self
+    }
 }
 
 impl Default for Options {
@@ -70,6 +802,344 @@ impl Default for Options {
             custom_http_client: None,
             public_suffix_url: PUBLIC_SUFFIX_FILE_URL.to_string(),
             public_suffix_file: None,
+            public_suffix_base64: None,
+            max_redirects: 10,
+            max_download_bytes: MAX_DOWNLOAD_SIZE,
+            max_retries: 2,
+            acceptable_content_types: ACCEPTABLE_CONTENT_TYPES
+                .iter()
+                .map(|content_type| content_type.to_string())
+                .collect(),
+            additional_schemes: Vec::new(),
+            lenient_ports: false,
+            assume_ascii: false,
+            strict_idn: false,
+            deny_private_suffix_results: false,
+            special_use_tlds: false,
+            offline: false,
+            supplemental_dir: None,
+            progress_callback: None,
+            on_loaded: None,
+            warning_sink: None,
+            miss_resolver: None,
+            cache_resolved_misses: false,
+            intern_domains: false,
+            trailing_dot_output: false,
+            suffix_preference: SuffixPreference::default(),
+            override_suffixes: HashMap::new(),
+            verbose_errors: false,
         }
     }
 }
+
+impl PartialEq for Options {
+    /// Compares every option except `custom_http_client`, which is ignored
+    fn eq(&self, other: &Self) -> bool {
+        // Destructured so that a new field cannot be left out by accident
+        let Self {
+            allow_private_tlds,
+            timeout,
+            custom_http_client: _,
+            public_suffix_url,
+            public_suffix_file,
+            public_suffix_base64,
+            max_redirects,
+            max_download_bytes,
+            max_retries,
+            acceptable_content_types,
+            additional_schemes,
+            lenient_ports,
+            assume_ascii,
+            strict_idn,
+            deny_private_suffix_results,
+            special_use_tlds,
+            offline,
+            supplemental_dir,
+            progress_callback,
+            on_loaded,
+            warning_sink,
+            miss_resolver,
+            cache_resolved_misses,
+            intern_domains,
+            trailing_dot_output,
+            suffix_preference,
+            override_suffixes,
+            verbose_errors,
+        } = self;
+
+        *allow_private_tlds == other.allow_private_tlds
+            && *timeout == other.timeout
+            && *public_suffix_url == other.public_suffix_url
+            && *public_suffix_file == other.public_suffix_file
+            && *public_suffix_base64 == other.public_suffix_base64
+            && *max_redirects == other.max_redirects
+            && *max_download_bytes == other.max_download_bytes
+            && *max_retries == other.max_retries
+            && *acceptable_content_types == other.acceptable_content_types
+            && *additional_schemes == other.additional_schemes
+            && *lenient_ports == other.lenient_ports
+            && *assume_ascii == other.assume_ascii
+            && *strict_idn == other.strict_idn
+            && *deny_private_suffix_results == other.deny_private_suffix_results
+            && *special_use_tlds == other.special_use_tlds
+            && *offline == other.offline
+            && *supplemental_dir == other.supplemental_dir
+            && *progress_callback == other.progress_callback
+            && *on_loaded == other.on_loaded
+            && *warning_sink == other.warning_sink
+            && *miss_resolver == other.miss_resolver
+            && *cache_resolved_misses == other.cache_resolved_misses
+            && *intern_domains == other.intern_domains
+            && *trailing_dot_output == other.trailing_dot_output
+            && *suffix_preference == other.suffix_preference
+            && *override_suffixes == other.override_suffixes
+            && *verbose_errors == other.verbose_errors
+    }
+}
+
+impl Eq for Options {}
+
+impl Hash for Options {
+    /// Hashes every option except `custom_http_client`, consistent with `eq`
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            allow_private_tlds,
+            timeout,
+            custom_http_client: _,
+            public_suffix_url,
+            public_suffix_file,
+            public_suffix_base64,
+            max_redirects,
+            max_download_bytes,
+            max_retries,
+            acceptable_content_types,
+            additional_schemes,
+            lenient_ports,
+            assume_ascii,
+            strict_idn,
+            deny_private_suffix_results,
+            special_use_tlds,
+            offline,
+            supplemental_dir,
+            progress_callback,
+            on_loaded,
+            warning_sink,
+            miss_resolver,
+            cache_resolved_misses,
+            intern_domains,
+            trailing_dot_output,
+            suffix_preference,
+            override_suffixes,
+            verbose_errors,
+        } = self;
+
+        allow_private_tlds.hash(state);
+        timeout.hash(state);
+        public_suffix_url.hash(state);
+        public_suffix_file.hash(state);
+        public_suffix_base64.hash(state);
+        max_redirects.hash(state);
+        max_download_bytes.hash(state);
+        max_retries.hash(state);
+        acceptable_content_types.hash(state);
+        additional_schemes.hash(state);
+        lenient_ports.hash(state);
+        assume_ascii.hash(state);
+        strict_idn.hash(state);
+        deny_private_suffix_results.hash(state);
+        special_use_tlds.hash(state);
+        offline.hash(state);
+        supplemental_dir.hash(state);
+        progress_callback.hash(state);
+        on_loaded.hash(state);
+        warning_sink.hash(state);
+        miss_resolver.hash(state);
+        cache_resolved_misses.hash(state);
+        intern_domains.hash(state);
+        trailing_dot_output.hash(state);
+        suffix_preference.hash(state);
+        // Map iteration order is unspecified, so entries are hashed sorted
+        let mut overrides: Vec<_> = override_suffixes.iter().collect();
+        overrides.sort_by(|a, b| a.0.cmp(b.0));
+        overrides.hash(state);
+        verbose_errors.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset_offline() {
+        let options = Options::new().preset(Preset::Offline);
+        assert!(options.offline);
+        assert!(!options.allow_private_tlds);
+        assert_eq!(options.max_redirects, 0);
+        assert!(!options.lenient_ports);
+    }
+
+    #[test]
+    fn test_preset_strict() {
+        let options = Options::new()
+            .allow_private_tlds(true)
+            .lenient_ports(true)
+            .preset(Preset::Strict);
+        assert!(!options.offline);
+        assert!(!options.allow_private_tlds);
+        assert_eq!(options.max_redirects, 0);
+        assert!(!options.lenient_ports);
+    }
+
+    #[test]
+    fn test_preset_lenient() {
+        let options = Options::new().offline(true).preset(Preset::Lenient);
+        assert!(!options.offline);
+        assert!(options.allow_private_tlds);
+        assert_eq!(options.max_redirects, Options::default().max_redirects);
+        assert!(options.lenient_ports);
+    }
+
+    #[test]
+    fn test_validate_timeout() {
+        assert!(Options::new().validate().is_ok());
+        assert!(Options::new().timeout(MIN_TIMEOUT).validate().is_ok());
+        assert!(Options::new().timeout(MAX_TIMEOUT).validate().is_ok());
+
+        for timeout in [
+            Duration::ZERO,
+            Duration::from_millis(999),
+            MAX_TIMEOUT + Duration::from_secs(1),
+            Duration::MAX,
+        ] {
+            match Options::new().timeout(timeout).validate() {
+                Err(TldError::InvalidOptions(msg)) => assert!(msg.contains("timeout")),
+                other => panic!("Expected InvalidOptions for {:?}, got {:?}", timeout, other),
+            }
+        }
+    }
+
+    #[test]
+    // The HTTP client's interior mutability is not part of `Hash` or `Eq`
+    #[allow(clippy::mutable_key_type)]
+    fn test_options_as_hash_map_key() {
+        use std::collections::HashMap;
+
+        let mut cache = HashMap::new();
+        cache.insert(Options::new(), "default");
+        cache.insert(Options::new().allow_private_tlds(true), "private");
+        cache.insert(
+            Options::new().timeout(Duration::from_secs(3)),
+            "short timeout",
+        );
+        assert_eq!(cache.len(), 3);
+
+        // Equal options find the same entry, whatever HTTP client they carry
+        assert_eq!(cache.get(&Options::default()), Some(&"default"));
+        let with_client = Options::new()
+            .allow_private_tlds(true)
+            .custom_http_client(Client::new());
+        assert_eq!(cache.get(&with_client), Some(&"private"));
+        assert_eq!(with_client, Options::new().allow_private_tlds(true));
+        assert_eq!(cache.get(&Options::new().max_retries(0)), None);
+
+        // Hooks compare by identity, so clones match and new closures do not
+        let hook: Arc<dyn Fn(&Fqdn) + Send + Sync> = Arc::new(|_: &Fqdn| {});
+        let hooked = Options::new().on_loaded(Arc::clone(&hook));
+        cache.insert(hooked.clone(), "hooked");
+        assert_eq!(cache.get(&hooked), Some(&"hooked"));
+        assert_eq!(cache.get(&Options::new().on_loaded(hook)), Some(&"hooked"));
+        assert_ne!(hooked, Options::new().on_loaded(Arc::new(|_: &Fqdn| {})));
+    }
+
+    /// Looks variables up in a fixed table instead of the process environment
+    fn from_table(vars: &[(&str, &str)]) -> Result<Options, TldError> {
+        let vars: HashMap<String, OsString> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), OsString::from(value)))
+            .collect();
+        Options::from_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_from_vars() {
+        assert_eq!(from_table(&[]).unwrap(), Options::default());
+
+        let options = from_table(&[
+            ("RUST_TLD_PRIVATE", "true"),
+            ("RUST_TLD_TIMEOUT", "2.5"),
+            ("RUST_TLD_URL", "https://mirror.example.com/psl.dat"),
+            ("RUST_TLD_FILE", " /etc/psl/list.dat "),
+            ("RUST_TLD_OFFLINE", "YES"),
+            ("RUST_TLD_SUPPLEMENTAL_DIR", "/etc/psl/extra"),
+            ("RUST_TLD_MAX_REDIRECTS", "0"),
+            ("RUST_TLD_MAX_RETRIES", "5"),
+            ("RUST_TLD_MAX_DOWNLOAD_BYTES", "1048576"),
+            ("RUST_TLD_SCHEMES", "MyApp, other://,"),
+            ("RUST_TLD_SPECIAL_USE", "on"),
+            ("RUST_TLD_VERBOSE_ERRORS", "0"),
+            ("RUST_TLD_BASE64", ""),
+        ])
+        .unwrap();
+        assert_eq!(
+            options,
+            Options::new()
+                .allow_private_tlds(true)
+                .timeout(Duration::from_millis(2500))
+                .public_suffix_url("https://mirror.example.com/psl.dat")
+                .public_suffix_file("/etc/psl/list.dat")
+                .offline(true)
+                .supplemental_dir("/etc/psl/extra")
+                .max_redirects(0)
+                .max_retries(5)
+                .max_download_bytes(1_048_576)
+                .additional_schemes(vec!["myapp".to_string(), "other".to_string()])
+                .special_use_tlds(true)
+                .verbose_errors(false)
+        );
+
+        for (name, value, expected) in [
+            (
+                "RUST_TLD_PRIVATE",
+                "maybe",
+                "RUST_TLD_PRIVATE must be a boolean",
+            ),
+            (
+                "RUST_TLD_TIMEOUT",
+                "30s",
+                "RUST_TLD_TIMEOUT must be a number of seconds",
+            ),
+            (
+                "RUST_TLD_TIMEOUT",
+                "-1",
+                "RUST_TLD_TIMEOUT must be a number of seconds",
+            ),
+            (
+                "RUST_TLD_MAX_RETRIES",
+                "-1",
+                "RUST_TLD_MAX_RETRIES must be a non-negative",
+            ),
+            ("RUST_TLD_TIMEOUT", "0", "timeout of 0ns is shorter"),
+        ] {
+            match from_table(&[(name, value)]) {
+                Err(TldError::InvalidOptions(msg)) => {
+                    assert!(msg.starts_with(expected), "{}={}: {}", name, value, msg)
+                }
+                other => panic!(
+                    "Expected InvalidOptions for {}={}, got {:?}",
+                    name, value, other
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_preset_keeps_other_options() {
+        let options = Options::new()
+            .timeout(Duration::from_secs(3))
+            .public_suffix_file("list.dat")
+            .preset(Preset::Strict);
+        assert_eq!(options.timeout, Duration::from_secs(3));
+        assert_eq!(options.public_suffix_file.as_deref(), Some("list.dat"));
+    }
+}
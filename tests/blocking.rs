@@ -0,0 +1,37 @@
+// file: tests/blocking.rs
+// description: runtime-free loading of the global manager (own binary to keep global state isolated)
+
+use rust_tld::{get_fqdn_sync, load_from_file_blocking, validate_origin_sync, MIN_DATA_SIZE};
+
+/// Builds a minimal public suffix list that passes all format checks
+fn test_suffix_list() -> String {
+    let mut data = String::from(
+        "// publicsuffix.org test data\n// ===BEGIN ICANN DOMAINS===\ncom\norg\nuk\nco.uk\n",
+    );
+    for i in 0..1000 {
+        data.push_str(&format!("tld{}\n", i));
+    }
+    data.push_str("// ===END ICANN DOMAINS===\n// Padding: ");
+    data.push_str(&"a".repeat(MIN_DATA_SIZE));
+    data
+}
+
+#[test]
+fn test_load_from_file_blocking_without_runtime() {
+    let temp_file = "/tmp/test_load_from_file_blocking.dat";
+    std::fs::write(temp_file, test_suffix_list()).unwrap();
+
+    let result = load_from_file_blocking(temp_file, None);
+    let _ = std::fs::remove_file(temp_file);
+    assert!(result.is_ok());
+
+    // No Tokio runtime exists on this thread
+    assert_eq!(
+        get_fqdn_sync("https://www.example.co.uk/path").unwrap(),
+        "example.co.uk"
+    );
+    assert!(validate_origin_sync(
+        "https://api.example.com",
+        &["example.com".to_string()]
+    ));
+}
@@ -0,0 +1,33 @@
+// file: tests/embedded.rs
+// description: checks that the global manager initializes from the embedded list without a network
+#![cfg(feature = "embedded")]
+
+use rust_tld::{build_info, get_fqdn, is_initialized, Fqdn, Options, Preset};
+
+#[tokio::test]
+async fn test_first_get_fqdn_works_offline() {
+    // Route any download through a closed port, so only the embedded list can work
+    std::env::set_var("HTTP_PROXY", "http://127.0.0.1:9");
+    std::env::set_var("HTTPS_PROXY", "http://127.0.0.1:9");
+
+    assert!(!is_initialized());
+    assert!(build_info().embedded);
+
+    assert_eq!(
+        get_fqdn("https://www.example.co.uk/path").await.unwrap(),
+        "example.co.uk"
+    );
+    assert!(is_initialized());
+    assert!(build_info().embedded);
+}
+
+#[tokio::test]
+async fn test_offline_preset_loads_embedded_list() {
+    let fqdn = Fqdn::new(Some(Options::new().preset(Preset::Offline)))
+        .await
+        .unwrap();
+    assert_eq!(
+        fqdn.get_fqdn("https://www.example.co.uk/path").unwrap(),
+        "example.co.uk"
+    );
+}
@@ -0,0 +1,32 @@
+// file: tests/from_env.rs
+// description: checks Options::from_env against the real process environment
+//
+// This binary holds a single test, so changing the environment cannot race
+// with other tests reading it.
+
+use rust_tld::{Options, TldError};
+use std::time::Duration;
+
+#[test]
+fn test_from_env() {
+    std::env::set_var("RUST_TLD_PRIVATE", "1");
+    std::env::set_var("RUST_TLD_TIMEOUT", "10");
+    std::env::set_var("RUST_TLD_FILE", "/data/public_suffix_list.dat");
+    let options = Options::from_env().unwrap();
+    assert!(options.allow_private_tlds);
+    assert_eq!(options.timeout, Duration::from_secs(10));
+    assert_eq!(
+        options.public_suffix_file.as_deref(),
+        Some("/data/public_suffix_list.dat")
+    );
+    assert_eq!(
+        options.public_suffix_url,
+        Options::default().public_suffix_url
+    );
+
+    std::env::set_var("RUST_TLD_OFFLINE", "sometimes");
+    assert!(matches!(
+        Options::from_env(),
+        Err(TldError::InvalidOptions(msg)) if msg.contains("RUST_TLD_OFFLINE")
+    ));
+}
@@ -0,0 +1,258 @@
+// file: tests/psl_conformance.rs
+// description: runs the official publicsuffix.org test vectors against a committed copy of the list
+
+use rust_tld::{Fqdn, Options, TldError, ETLD_GROUP_MAX};
+
+/// Full Public Suffix List snapshot used by the vectors
+const PSL_FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/public_suffix_list.dat");
+
+/// Official `checkPublicSuffix` vectors from publicsuffix.org
+const VECTORS: &str = include_str!("fixtures/test_psl.txt");
+
+/// Vectors for unlisted TLDs, which the PSL algorithm treats as public suffixes
+/// through its implicit `*` rule. This crate deliberately rejects hosts without
+/// a listed suffix with `TldError::InvalidTld` instead.
+const UNLISTED_TLD_VECTORS: &[&str] = &[
+    "example.example",
+    "b.example.example",
+    "a.b.example.example",
+];
+
+/// Parses `checkPublicSuffix('input', 'expected');` lines into (input, expected)
+fn parse_vectors() -> Vec<(Option<String>, Option<String>)> {
+    fn argument(arg: &str) -> Option<String> {
+        let arg = arg.trim();
+        (arg != "null").then(|| arg.trim_matches('\'').to_string())
+    }
+
+    VECTORS
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("checkPublicSuffix("))
+        .filter_map(|call| call.strip_suffix(");"))
+        .filter_map(|args| args.split_once(','))
+        .map(|(input, expected)| (argument(input), argument(expected)))
+        .collect()
+}
+
+fn load_full_list() -> Fqdn {
+    let options = Options::new().allow_private_tlds(true);
+    Fqdn::from_file_blocking(PSL_FIXTURE, Some(options)).unwrap()
+}
+
+#[test]
+fn test_official_vectors() {
+    let fqdn = load_full_list();
+    let vectors = parse_vectors();
+    assert!(vectors.len() > 70, "only {} vectors parsed", vectors.len());
+
+    let mut failures = Vec::new();
+    for (input, expected) in &vectors {
+        let input = input.as_deref().unwrap_or("");
+        if UNLISTED_TLD_VECTORS.contains(&input) {
+            continue;
+        }
+
+        let result = fqdn.get_fqdn(input);
+        let conforms = match (expected, &result) {
+            (Some(expected), Ok(domain)) => domain == expected,
+            (None, Err(_)) => true,
+            _ => false,
+        };
+        if !conforms {
+            failures.push(format!(
+                "{:?}: expected {:?}, got {:?}",
+                input, expected, result
+            ));
+        }
+
+        // The registrable domain is exactly one label above the public suffix
+        if let Some(expected) = expected {
+            let suffix = fqdn.public_suffix(input);
+            let (_, expected_suffix) = expected.split_once('.').unwrap();
+            if suffix.as_deref() != Ok(expected_suffix) {
+                failures.push(format!(
+                    "public_suffix({:?}): expected {:?}, got {:?}",
+                    input, expected_suffix, suffix
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "non-conforming vectors:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_unlisted_tld_deviation() {
+    let fqdn = load_full_list();
+    for input in UNLISTED_TLD_VECTORS {
+        assert_eq!(
+            fqdn.get_fqdn(input),
+            Err(TldError::InvalidTld(None)),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_deep_private_suffixes() {
+    // Every rule fits the fixed buckets, so nothing is dropped while parsing
+    let list = std::fs::read_to_string(PSL_FIXTURE).unwrap();
+    let deepest = list
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .map(|rule| rule.trim_start_matches('!').split('.').count())
+        .max()
+        .unwrap();
+    assert!(deepest <= ETLD_GROUP_MAX, "rule with {} labels", deepest);
+
+    let host = "bucket.s3.dualstack.us-east-1.amazonaws.com";
+    let fqdn = load_full_list();
+    assert_eq!(fqdn.get_fqdn(host).unwrap(), host);
+    assert_eq!(
+        fqdn.public_suffix(host).unwrap(),
+        "s3.dualstack.us-east-1.amazonaws.com"
+    );
+    assert_eq!(
+        fqdn.get_fqdn(&format!("https://www.{}/key", host)).unwrap(),
+        host
+    );
+
+    // Without private rules the ICANN suffix applies
+    let icann = Fqdn::from_file_blocking(PSL_FIXTURE, None).unwrap();
+    assert_eq!(icann.get_fqdn(host).unwrap(), "amazonaws.com");
+}
+
+#[test]
+fn test_parse_matches_reference() {
+    // A straightforward reading of the list: every rule, tagged with its section
+    let list = std::fs::read_to_string(PSL_FIXTURE).unwrap();
+    let mut icann = false;
+    let mut reference = Vec::new();
+    for line in list.lines().map(str::trim) {
+        if line.contains("===BEGIN ICANN DOMAINS===") {
+            icann = true;
+        } else if line.contains("===END ICANN DOMAINS===") {
+            icann = false;
+        } else if !line.is_empty() && !line.starts_with("//") {
+            reference.push((line.to_lowercase(), icann));
+        }
+    }
+
+    for allow_private in [false, true] {
+        let options = Options::new().allow_private_tlds(allow_private);
+        let fqdn = Fqdn::from_file_blocking(PSL_FIXTURE, Some(options)).unwrap();
+
+        let mut loaded = Vec::new();
+        fqdn.for_each_suffix(|rule| loaded.push(rule.to_string()));
+        loaded.sort();
+
+        let mut expected: Vec<String> = reference
+            .iter()
+            .filter(|(_, icann)| *icann || allow_private)
+            .map(|(rule, _)| rule.clone())
+            .collect();
+        expected.sort();
+        expected.dedup();
+
+        assert_eq!(loaded.len(), fqdn.total());
+        assert!(
+            loaded == expected,
+            "rules differ (private: {})",
+            allow_private
+        );
+    }
+}
+
+#[test]
+fn test_suffixes_with_suffix_matches_full_scan() {
+    let fqdn = load_full_list();
+
+    let mut exact_rules = Vec::new();
+    fqdn.for_each_suffix(|rule| {
+        if !rule.starts_with("*.") && !rule.starts_with('!') {
+            exact_rules.push(rule.to_string());
+        }
+    });
+
+    // Every TLD and every second-level suffix as a parent
+    let parents: Vec<String> = exact_rules
+        .iter()
+        .filter(|rule| rule.matches('.').count() <= 1)
+        .cloned()
+        .collect();
+    assert!(parents.len() > 1000);
+
+    for parent in &parents {
+        let tail = format!(".{}", parent);
+        let mut expected: Vec<String> = exact_rules
+            .iter()
+            .filter(|rule| rule.ends_with(&tail))
+            .cloned()
+            .collect();
+        expected.sort();
+        assert_eq!(fqdn.suffixes_with_suffix(parent), expected, "{}", parent);
+    }
+
+    assert!(fqdn
+        .suffixes_with_suffix("uk")
+        .contains(&"co.uk".to_string()));
+    assert_eq!(
+        fqdn.suffixes_with_suffix(".UK."),
+        fqdn.suffixes_with_suffix("uk")
+    );
+}
+
+#[test]
+fn test_suffix_queries_agree_on_bare_suffixes() {
+    let fqdn = load_full_list();
+    let suffix_error = Err(TldError::InvalidUrl(Some(
+        "host is a public suffix".to_string(),
+    )));
+
+    let mut rules = Vec::new();
+    fqdn.for_each_suffix(|rule| rules.push(rule.to_string()));
+    assert_eq!(rules.len(), fqdn.total());
+
+    let mut failures = Vec::new();
+    for rule in &rules {
+        // Wildcards are checked through one concrete label, exceptions are
+        // registrable domains rather than suffixes
+        let (host, is_suffix) = match (rule.strip_prefix("*."), rule.strip_prefix('!')) {
+            (Some(base), _) => (format!("label.{}", base), true),
+            (_, Some(exception)) => (exception.to_string(), false),
+            _ => (rule.clone(), true),
+        };
+
+        let expected_fqdn = if is_suffix {
+            suffix_error.clone()
+        } else {
+            Ok(host.clone())
+        };
+        let got_fqdn = fqdn.get_fqdn(&host);
+        if fqdn.is_public_suffix(&host) != is_suffix
+            || got_fqdn != expected_fqdn
+            || (is_suffix && fqdn.public_suffix(&host).as_deref() != Ok(host.as_str()))
+        {
+            failures.push(format!(
+                "{:?}: is_public_suffix {}, get_fqdn {:?}, public_suffix {:?}",
+                rule,
+                fqdn.is_public_suffix(&host),
+                got_fqdn,
+                fqdn.public_suffix(&host)
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} inconsistent rules:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}